@@ -0,0 +1,73 @@
+use std::ffi::CString;
+
+use openvr_sys as sys;
+use openvr_sys::{DriverHandle_t, DriverId_t};
+
+use crate::{get_string, property, DriverManager, System, TrackedDeviceIndex};
+
+/// An installed driver, as listed by `DriverManager::drivers`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DriverInfo {
+    pub index: DriverId_t,
+    /// Empty if OpenVR didn't report a name
+    pub name: String,
+    /// The property container of the driver, as found in the `ParentDriver_Uint64` property of its devices. `None`
+    /// for drivers that have no handle, such as drivers that aren't loaded.
+    pub handle: Option<DriverHandle_t>,
+    pub enabled: bool,
+}
+
+impl DriverManager {
+    pub fn driver_count(&self) -> u32 {
+        unsafe { self.0.GetDriverCount.unwrap()() }
+    }
+
+    /// Returns `None` if `index` is out of range.
+    pub fn driver_name(&self, index: DriverId_t) -> Option<String> {
+        unsafe {
+            get_string(|ptr, n| self.0.GetDriverName.unwrap()(index, ptr, n))
+                .map(|x| x.to_string_lossy().into_owned())
+        }
+    }
+
+    /// Returns the property container handle of the named driver, or `None` if no such driver is installed.
+    pub fn driver_handle(&self, name: &str) -> Option<DriverHandle_t> {
+        let name = CString::new(name).unwrap();
+        let handle = unsafe { self.0.GetDriverHandle.unwrap()(name.as_ptr() as _) };
+        if handle == sys::k_ulInvalidDriverHandle {
+            None
+        } else {
+            Some(handle)
+        }
+    }
+
+    /// Whether the driver is enabled in the SteamVR settings
+    pub fn is_enabled(&self, index: DriverId_t) -> bool {
+        unsafe { self.0.IsEnabled.unwrap()(index) }
+    }
+
+    /// Iterates over all installed drivers, including disabled and unloaded ones.
+    pub fn drivers(&self) -> impl Iterator<Item = DriverInfo> + '_ {
+        (0..self.driver_count()).map(move |index| {
+            let name = self.driver_name(index).unwrap_or_default();
+            DriverInfo {
+                index,
+                handle: self.driver_handle(&name),
+                enabled: self.is_enabled(index),
+                name,
+            }
+        })
+    }
+
+    /// Finds the driver that provides a tracked device, using its `ParentDriver_Uint64` property.
+    pub fn driver_for_device(
+        &self,
+        system: &System,
+        device: TrackedDeviceIndex,
+    ) -> Option<DriverInfo> {
+        let handle = system
+            .uint64_tracked_device_property(device, property::ParentDriver_Uint64)
+            .ok()?;
+        self.drivers().find(|x| x.handle == Some(handle))
+    }
+}
@@ -0,0 +1,93 @@
+//! Window and viewport placement for headsets running in extended mode, where the HMD shows up as a monitor on the
+//! desktop instead of being driven directly by the compositor.
+
+use openvr_sys as sys;
+
+use crate::{ExtendedDisplay, Eye};
+
+/// The HMD's area of the desktop, in desktop pixel coordinates
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct WindowBounds {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// The area of the window one eye is shown in, in pixels
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Viewport {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// The DXGI adapter and output the HMD is connected to
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct DxgiOutputInfo {
+    pub adapter_index: i32,
+    pub adapter_output_index: i32,
+}
+
+impl ExtendedDisplay {
+    /** Size and position that the window needs to be on the VR display. */
+    pub fn window_bounds(&self) -> WindowBounds {
+        let mut bounds = WindowBounds {
+            x: 0,
+            y: 0,
+            width: 0,
+            height: 0,
+        };
+        unsafe {
+            self.0.GetWindowBounds.unwrap()(
+                &mut bounds.x,
+                &mut bounds.y,
+                &mut bounds.width,
+                &mut bounds.height,
+            )
+        };
+        bounds
+    }
+
+    /** Gets the viewport in the frame buffer to draw the output of the distortion into */
+    pub fn eye_output_viewport(&self, eye: Eye) -> Viewport {
+        let mut viewport = Viewport {
+            x: 0,
+            y: 0,
+            width: 0,
+            height: 0,
+        };
+        unsafe {
+            self.0.GetEyeOutputViewport.unwrap()(
+                eye as sys::EVREye,
+                &mut viewport.x,
+                &mut viewport.y,
+                &mut viewport.width,
+                &mut viewport.height,
+            )
+        };
+        viewport
+    }
+
+    /** [D3D10/11 Only]
+     * Returns the adapter index and output index that the user should pass into EnumAdapters and EnumOutputs to
+     * create the device and swap chain in DX10 and DX11. If an error occurs both indices will be set to -1. */
+    pub fn dxgi_output_info(&self) -> Option<DxgiOutputInfo> {
+        let mut info = DxgiOutputInfo {
+            adapter_index: -1,
+            adapter_output_index: -1,
+        };
+        unsafe {
+            self.0.GetDXGIOutputInfo.unwrap()(
+                &mut info.adapter_index,
+                &mut info.adapter_output_index,
+            )
+        };
+        if info.adapter_index == -1 && info.adapter_output_index == -1 {
+            None
+        } else {
+            Some(info)
+        }
+    }
+}
@@ -0,0 +1,400 @@
+//! A typed model of the SteamVR action manifest (`actions.json`).
+//!
+//! Build an `ActionManifest`, check it with `ActionManifest::validate`, write the output of `ActionManifest::to_json`
+//! to disk and pass that path to `Input::set_action_manifest_path`.
+
+use std::collections::{BTreeMap, HashSet};
+use std::{error::Error, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ActionManifest {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub default_bindings: Vec<DefaultBinding>,
+    #[serde(default)]
+    pub actions: Vec<Action>,
+    #[serde(default)]
+    pub action_sets: Vec<ActionSet>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub localization: Vec<Localization>,
+}
+
+/// The binding file to load for a controller type when the user has not picked one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DefaultBinding {
+    /// e.g. `knuckles`, `vive_controller` or `oculus_touch`
+    pub controller_type: String,
+    /// Path to the binding file, relative to the action manifest
+    pub binding_url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionSet {
+    /// e.g. `/actions/main`
+    pub name: String,
+    pub usage: ActionSetUsage,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ActionSetUsage {
+    /// The user can bind the actions differently for each hand
+    LeftRight,
+    /// The same bindings are used for both hands
+    Single,
+    /// The action set is not shown in the binding UI
+    Hidden,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Action {
+    /// e.g. `/actions/main/in/fire` or `/actions/main/out/haptic`
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: ActionType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requirement: Option<ActionRequirement>,
+    /// The skeleton path for `ActionType::Skeleton` actions, e.g. `/skeleton/hand/left`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skeleton: Option<String>,
+}
+
+impl Action {
+    pub fn new(name: impl Into<String>, ty: ActionType) -> Self {
+        Action {
+            name: name.into(),
+            ty,
+            requirement: None,
+            skeleton: None,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ActionType {
+    Boolean,
+    Vector1,
+    Vector2,
+    Vector3,
+    Vibration,
+    Pose,
+    Skeleton,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ActionRequirement {
+    /// The user will be warned if this action is not bound
+    Mandatory,
+    /// The user will be told if this action is not bound, but may ignore it
+    Suggested,
+    Optional,
+}
+
+/// Display names for action sets and actions in a single language
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Localization {
+    /// e.g. `en_US`
+    pub language_tag: String,
+    /// Maps action set and action paths to their display names
+    #[serde(flatten)]
+    pub names: BTreeMap<String, String>,
+}
+
+/// A problem found by `ActionManifest::validate`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ManifestError {
+    /// The action set name is not of the form `/actions/<set>`
+    InvalidActionSetPath(String),
+    /// The action name is not of the form `/actions/<set>/in/<name>` or `/actions/<set>/out/<name>`
+    InvalidActionPath(String),
+    DuplicateActionSet(String),
+    DuplicateAction(String),
+    /// The action belongs to an action set that is not declared in the manifest
+    UndeclaredActionSet {
+        action: String,
+        action_set: String,
+    },
+    /// Vibration actions must be outputs and all other actions must be inputs
+    WrongDirection(String),
+    /// A skeleton action without a skeleton path
+    MissingSkeleton(String),
+    DuplicateDefaultBinding(String),
+    DuplicateLanguage(String),
+    /// A localized name for a path that is neither a declared action set nor action
+    UnknownLocalizedPath {
+        language_tag: String,
+        path: String,
+    },
+}
+
+impl Display for ManifestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use self::ManifestError::*;
+        match self {
+            InvalidActionSetPath(x) => write!(f, "invalid action set path {:?}", x),
+            InvalidActionPath(x) => write!(f, "invalid action path {:?}", x),
+            DuplicateActionSet(x) => write!(f, "duplicate action set {:?}", x),
+            DuplicateAction(x) => write!(f, "duplicate action {:?}", x),
+            UndeclaredActionSet { action, action_set } => write!(
+                f,
+                "action {:?} belongs to undeclared action set {:?}",
+                action, action_set
+            ),
+            WrongDirection(x) => write!(
+                f,
+                "action {:?} must be under /out/ if and only if it is a vibration action",
+                x
+            ),
+            MissingSkeleton(x) => write!(f, "skeleton action {:?} has no skeleton path", x),
+            DuplicateDefaultBinding(x) => {
+                write!(f, "duplicate default binding for controller type {:?}", x)
+            }
+            DuplicateLanguage(x) => write!(f, "duplicate localization for {:?}", x),
+            UnknownLocalizedPath { language_tag, path } => write!(
+                f,
+                "localization {:?} names unknown path {:?}",
+                language_tag, path
+            ),
+        }
+    }
+}
+impl Error for ManifestError {}
+
+/// Returns the set name of a valid `/actions/<set>` path.
+fn parse_action_set_path(path: &str) -> Option<&str> {
+    let set = path.strip_prefix("/actions/")?;
+    if is_path_segment(set) {
+        Some(set)
+    } else {
+        None
+    }
+}
+
+/// Returns the action set path and direction of a valid `/actions/<set>/<in|out>/<name>` path.
+fn parse_action_path(path: &str) -> Option<(&str, &str)> {
+    let rest = path.strip_prefix("/actions/")?;
+    let mut segments = rest.split('/');
+    let set = segments.next()?;
+    let direction = segments.next()?;
+    let name = segments.next()?;
+    if segments.next().is_some()
+        || !is_path_segment(set)
+        || !is_path_segment(name)
+        || (direction != "in" && direction != "out")
+    {
+        return None;
+    }
+    Some((&path[.."/actions/".len() + set.len()], direction))
+}
+
+fn is_path_segment(x: &str) -> bool {
+    !x.is_empty()
+        && x.chars()
+            .all(|c| !c.is_whitespace() && !c.is_control() && c != '/')
+}
+
+impl ActionManifest {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Pretty-printed JSON in the format SteamVR expects
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Checks the manifest for mistakes that would otherwise only be reported by SteamVR at runtime.
+    ///
+    /// Paths are compared case-insensitively, as SteamVR does.
+    pub fn validate(&self) -> Result<(), Vec<ManifestError>> {
+        let mut errors = Vec::new();
+
+        let mut sets = HashSet::new();
+        for set in &self.action_sets {
+            if parse_action_set_path(&set.name).is_none() {
+                errors.push(ManifestError::InvalidActionSetPath(set.name.clone()));
+            }
+            if !sets.insert(set.name.to_ascii_lowercase()) {
+                errors.push(ManifestError::DuplicateActionSet(set.name.clone()));
+            }
+        }
+
+        let mut actions = HashSet::new();
+        for action in &self.actions {
+            match parse_action_path(&action.name) {
+                None => errors.push(ManifestError::InvalidActionPath(action.name.clone())),
+                Some((set, direction)) => {
+                    if !sets.contains(&set.to_ascii_lowercase()) {
+                        errors.push(ManifestError::UndeclaredActionSet {
+                            action: action.name.clone(),
+                            action_set: set.to_string(),
+                        });
+                    }
+                    if (direction == "out") != (action.ty == ActionType::Vibration) {
+                        errors.push(ManifestError::WrongDirection(action.name.clone()));
+                    }
+                }
+            }
+            if action.ty == ActionType::Skeleton && action.skeleton.is_none() {
+                errors.push(ManifestError::MissingSkeleton(action.name.clone()));
+            }
+            if !actions.insert(action.name.to_ascii_lowercase()) {
+                errors.push(ManifestError::DuplicateAction(action.name.clone()));
+            }
+        }
+
+        let mut controller_types = HashSet::new();
+        for binding in &self.default_bindings {
+            if !controller_types.insert(binding.controller_type.as_str()) {
+                errors.push(ManifestError::DuplicateDefaultBinding(
+                    binding.controller_type.clone(),
+                ));
+            }
+        }
+
+        let mut languages = HashSet::new();
+        for localization in &self.localization {
+            if !languages.insert(localization.language_tag.as_str()) {
+                errors.push(ManifestError::DuplicateLanguage(
+                    localization.language_tag.clone(),
+                ));
+            }
+            for path in localization.names.keys() {
+                let path_lower = path.to_ascii_lowercase();
+                if !sets.contains(&path_lower) && !actions.contains(&path_lower) {
+                    errors.push(ManifestError::UnknownLocalizedPath {
+                        language_tag: localization.language_tag.clone(),
+                        path: path.clone(),
+                    });
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn manifest() -> ActionManifest {
+        let mut names = BTreeMap::new();
+        names.insert("/actions/main".to_string(), "Main".to_string());
+        names.insert("/actions/main/in/fire".to_string(), "Fire".to_string());
+        ActionManifest {
+            default_bindings: vec![DefaultBinding {
+                controller_type: "knuckles".into(),
+                binding_url: "bindings_knuckles.json".into(),
+            }],
+            actions: vec![
+                Action {
+                    requirement: Some(ActionRequirement::Mandatory),
+                    ..Action::new("/actions/main/in/fire", ActionType::Boolean)
+                },
+                Action {
+                    skeleton: Some("/skeleton/hand/left".into()),
+                    ..Action::new("/actions/main/in/hand_left", ActionType::Skeleton)
+                },
+                Action::new("/actions/main/out/haptic", ActionType::Vibration),
+            ],
+            action_sets: vec![ActionSet {
+                name: "/actions/main".into(),
+                usage: ActionSetUsage::LeftRight,
+            }],
+            localization: vec![Localization {
+                language_tag: "en_US".into(),
+                names,
+            }],
+        }
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let manifest = manifest();
+        let json = manifest.to_json().unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["actions"][0]["type"], "boolean");
+        assert_eq!(value["actions"][0]["requirement"], "mandatory");
+        assert_eq!(value["actions"][1]["skeleton"], "/skeleton/hand/left");
+        assert!(value["actions"][2].get("skeleton").is_none());
+        assert_eq!(value["action_sets"][0]["usage"], "leftright");
+        assert_eq!(value["localization"][0]["language_tag"], "en_US");
+        assert_eq!(value["localization"][0]["/actions/main/in/fire"], "Fire");
+        assert_eq!(ActionManifest::from_json(&json).unwrap(), manifest);
+    }
+
+    #[test]
+    fn valid_manifest() {
+        assert_eq!(manifest().validate(), Ok(()));
+    }
+
+    #[test]
+    fn invalid_manifest() {
+        let mut manifest = manifest();
+        manifest.action_sets.push(ActionSet {
+            name: "/actions/Main".into(),
+            usage: ActionSetUsage::Single,
+        });
+        manifest.action_sets.push(ActionSet {
+            name: "/action/other".into(),
+            usage: ActionSetUsage::Hidden,
+        });
+        manifest
+            .actions
+            .push(Action::new("/actions/main/in/Fire", ActionType::Boolean));
+        manifest
+            .actions
+            .push(Action::new("/actions/main/fire", ActionType::Boolean));
+        manifest
+            .actions
+            .push(Action::new("/actions/missing/in/fire", ActionType::Vector1));
+        manifest.actions.push(Action::new(
+            "/actions/main/in/rumble",
+            ActionType::Vibration,
+        ));
+        manifest.actions.push(Action::new(
+            "/actions/main/in/hand_right",
+            ActionType::Skeleton,
+        ));
+        manifest.default_bindings.push(DefaultBinding {
+            controller_type: "knuckles".into(),
+            binding_url: "other.json".into(),
+        });
+        manifest.localization[0]
+            .names
+            .insert("/actions/main/in/jump".into(), "Jump".into());
+
+        assert_eq!(
+            manifest.validate(),
+            Err(vec![
+                ManifestError::DuplicateActionSet("/actions/Main".into()),
+                ManifestError::InvalidActionSetPath("/action/other".into()),
+                ManifestError::DuplicateAction("/actions/main/in/Fire".into()),
+                ManifestError::InvalidActionPath("/actions/main/fire".into()),
+                ManifestError::UndeclaredActionSet {
+                    action: "/actions/missing/in/fire".into(),
+                    action_set: "/actions/missing".into(),
+                },
+                ManifestError::WrongDirection("/actions/main/in/rumble".into()),
+                ManifestError::MissingSkeleton("/actions/main/in/hand_right".into()),
+                ManifestError::DuplicateDefaultBinding("knuckles".into()),
+                ManifestError::UnknownLocalizedPath {
+                    language_tag: "en_US".into(),
+                    path: "/actions/main/in/jump".into(),
+                },
+            ])
+        );
+    }
+}
@@ -0,0 +1,678 @@
+use std::{
+    error::Error,
+    ffi::{CStr, CString},
+    fmt::Display,
+    mem::MaybeUninit,
+    path::Path,
+};
+
+use openvr_sys::{
+    k_ulInvalidActionHandle, k_ulInvalidActionSetHandle, k_ulInvalidInputValueHandle,
+    k_unMaxActionOriginCount, ETrackingUniverseOrigin, EVRInputError,
+    EVRInputError_VRInputError_None, InputAnalogActionData_t, InputDigitalActionData_t,
+    InputOriginInfo_t, InputPoseActionData_t, InputSkeletalActionData_t, VRActionHandle_t,
+    VRActionSetHandle_t, VRActiveActionSet_t, VRInputValueHandle_t,
+};
+
+use crate::{Input, TrackedDeviceIndex, TrackedDevicePose, TrackingUniverseOrigin};
+
+pub mod manifest;
+pub mod skeleton;
+
+pub use self::manifest::ActionManifest;
+pub use self::skeleton::{
+    BoneIndex, BoneTransform, SkeletalMotionRange, SkeletalReferencePose, SkeletalSummaryData,
+    SkeletalTrackingLevel, SkeletalTransformSpace, SummaryType,
+};
+
+#[derive(Debug)]
+pub struct VRInputError(pub EVRInputError);
+impl Display for VRInputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EVRInputError {}", self.0)
+    }
+}
+impl Error for VRInputError {}
+impl From<EVRInputError> for VRInputError {
+    fn from(err: EVRInputError) -> Self {
+        VRInputError(err)
+    }
+}
+
+/// Handle to an action set, e.g. `/actions/main`
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ActionSetHandle(pub VRActionSetHandle_t);
+
+impl ActionSetHandle {
+    pub const INVALID: ActionSetHandle = ActionSetHandle(k_ulInvalidActionSetHandle);
+}
+
+/// Handle to an action, e.g. `/actions/main/in/fire`
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ActionHandle(pub VRActionHandle_t);
+
+impl ActionHandle {
+    pub const INVALID: ActionHandle = ActionHandle(k_ulInvalidActionHandle);
+}
+
+/// Handle to an input source or device path, e.g. `/user/hand/left`
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct InputSourceHandle(pub VRInputValueHandle_t);
+
+impl InputSourceHandle {
+    pub const INVALID: InputSourceHandle = InputSourceHandle(k_ulInvalidInputValueHandle);
+}
+
+/// An action set to activate with `Input::update_action_state`
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ActiveActionSet(VRActiveActionSet_t);
+
+impl ActiveActionSet {
+    pub fn new(action_set: ActionSetHandle) -> Self {
+        ActiveActionSet(VRActiveActionSet_t {
+            ulActionSet: action_set.0,
+            ulRestrictedToDevice: k_ulInvalidInputValueHandle,
+            ulSecondaryActionSet: k_ulInvalidActionSetHandle,
+            unPadding: 0,
+            nPriority: 0,
+        })
+    }
+
+    /// Only activate the set for actions bound to this device, e.g. `/user/hand/left`.
+    ///
+    /// If a secondary action set is also given, it is activated for all other devices.
+    pub fn restrict_to_device(mut self, device: InputSourceHandle) -> Self {
+        self.0.ulRestrictedToDevice = device.0;
+        self
+    }
+
+    /// The action set to activate for all devices other than the one given to `restrict_to_device`
+    pub fn with_secondary_action_set(mut self, action_set: ActionSetHandle) -> Self {
+        self.0.ulSecondaryActionSet = action_set.0;
+        self
+    }
+
+    /// The priority of this action set relative to other action sets. Any inputs bound to a source (e.g. trigger,
+    /// joystick, trackpad) with priority higher than other sets will be used instead of lower priority sets.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.0.nPriority = priority;
+        self
+    }
+
+    pub fn action_set(&self) -> ActionSetHandle {
+        ActionSetHandle(self.0.ulActionSet)
+    }
+
+    pub fn restricted_to_device(&self) -> Option<InputSourceHandle> {
+        if self.0.ulRestrictedToDevice == k_ulInvalidInputValueHandle {
+            None
+        } else {
+            Some(InputSourceHandle(self.0.ulRestrictedToDevice))
+        }
+    }
+
+    pub fn secondary_action_set(&self) -> Option<ActionSetHandle> {
+        if self.0.ulSecondaryActionSet == k_ulInvalidActionSetHandle {
+            None
+        } else {
+            Some(ActionSetHandle(self.0.ulSecondaryActionSet))
+        }
+    }
+
+    pub fn priority(&self) -> i32 {
+        self.0.nPriority
+    }
+}
+
+/// Builds the list of action sets passed to `Input::update_action_state`, ordered from highest to lowest priority.
+///
+/// Sets with equal priority keep the order they were added in.
+#[derive(Debug, Clone, Default)]
+pub struct ActiveActionSets {
+    sets: Vec<ActiveActionSet>,
+}
+
+impl ActiveActionSets {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn add(mut self, set: ActiveActionSet) -> Self {
+        self.sets.push(set);
+        self
+    }
+
+    pub fn add_with_priority(self, action_set: ActionSetHandle, priority: i32) -> Self {
+        self.add(ActiveActionSet::new(action_set).with_priority(priority))
+    }
+
+    pub fn build(mut self) -> Vec<ActiveActionSet> {
+        self.sets.sort_by(|a, b| b.priority().cmp(&a.priority()));
+        self.sets
+    }
+}
+
+/// State of a digital (boolean) action
+#[derive(Debug, Copy, Clone)]
+pub struct DigitalActionData {
+    /// Whether or not this action is currently available to be bound in the active action set
+    pub active: bool,
+    /// The origin that caused this action's current state
+    pub active_origin: InputSourceHandle,
+    /// The current state of this action; will be true if currently pressed
+    pub state: bool,
+    /// This is true if the state has changed since the last frame
+    pub changed: bool,
+    /// Time relative to now when this event happened. Will be negative to indicate a past time.
+    pub update_time: f32,
+}
+
+impl From<InputDigitalActionData_t> for DigitalActionData {
+    fn from(x: InputDigitalActionData_t) -> Self {
+        DigitalActionData {
+            active: x.bActive,
+            active_origin: InputSourceHandle(x.activeOrigin),
+            state: x.bState,
+            changed: x.bChanged,
+            update_time: x.fUpdateTime,
+        }
+    }
+}
+
+/// State of an analog (vector1, vector2 or vector3) action
+#[derive(Debug, Copy, Clone)]
+pub struct AnalogActionData {
+    /// Whether or not this action is currently available to be bound in the active action set
+    pub active: bool,
+    /// The origin that caused this action's current state
+    pub active_origin: InputSourceHandle,
+    /// The current state of this action; will be delta updates for mouse actions
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    /// Deltas since the previous call to UpdateActionState()
+    pub delta_x: f32,
+    pub delta_y: f32,
+    pub delta_z: f32,
+    /// Time relative to now when this event happened. Will be negative to indicate a past time.
+    pub update_time: f32,
+}
+
+impl From<InputAnalogActionData_t> for AnalogActionData {
+    fn from(x: InputAnalogActionData_t) -> Self {
+        AnalogActionData {
+            active: x.bActive,
+            active_origin: InputSourceHandle(x.activeOrigin),
+            x: x.x,
+            y: x.y,
+            z: x.z,
+            delta_x: x.deltaX,
+            delta_y: x.deltaY,
+            delta_z: x.deltaZ,
+            update_time: x.fUpdateTime,
+        }
+    }
+}
+
+/// State of a pose action
+#[derive(Debug, Copy, Clone)]
+pub struct PoseActionData {
+    /// Whether or not this action is currently available to be bound in the active action set
+    pub active: bool,
+    /// The origin that caused this action's current state
+    pub active_origin: InputSourceHandle,
+    /// The current state of this action
+    pub pose: TrackedDevicePose,
+}
+
+impl From<InputPoseActionData_t> for PoseActionData {
+    fn from(x: InputPoseActionData_t) -> Self {
+        PoseActionData {
+            active: x.bActive,
+            active_origin: InputSourceHandle(x.activeOrigin),
+            pose: x.pose.into(),
+        }
+    }
+}
+
+/// State of a skeletal action
+#[derive(Debug, Copy, Clone)]
+pub struct SkeletalActionData {
+    /// Whether or not this action is currently available to be bound in the active action set
+    pub active: bool,
+    /// The origin that caused this action's current state
+    pub active_origin: InputSourceHandle,
+}
+
+impl From<InputSkeletalActionData_t> for SkeletalActionData {
+    fn from(x: InputSkeletalActionData_t) -> Self {
+        SkeletalActionData {
+            active: x.bActive,
+            active_origin: InputSourceHandle(x.activeOrigin),
+        }
+    }
+}
+
+/// Which parts of an origin's name to include in `Input::origin_localized_name`. Combine with `|`.
+pub type InputStringBits = i32;
+
+pub mod input_string {
+    use super::InputStringBits;
+    use openvr_sys as sys;
+
+    pub const HAND: InputStringBits = sys::EVRInputStringBits_VRInputString_Hand as InputStringBits;
+    pub const CONTROLLER_TYPE: InputStringBits =
+        sys::EVRInputStringBits_VRInputString_ControllerType as InputStringBits;
+    pub const INPUT_SOURCE: InputStringBits =
+        sys::EVRInputStringBits_VRInputString_InputSource as InputStringBits;
+    pub const ALL: InputStringBits = sys::EVRInputStringBits_VRInputString_All as InputStringBits;
+}
+
+/// The physical device and render model component an input origin belongs to
+#[derive(Debug, Clone)]
+pub struct InputOriginInfo {
+    pub device_path: InputSourceHandle,
+    pub tracked_device_index: TrackedDeviceIndex,
+    pub render_model_component_name: String,
+}
+
+impl Input {
+    /** Sets the path to the action manifest JSON file that is used by this application. If this information
+     * was set on the Steam partner site, calls to this function are ignored. If the Steam partner site
+     * setting and the path provided by this call are different, VRInputError_MismatchedActionManifest is returned.
+     * This call must be made before the first call to UpdateActionState or IVRSystem::PollNextEvent. */
+    pub fn set_action_manifest_path(
+        &self,
+        action_manifest_path: &Path,
+    ) -> Result<(), VRInputError> {
+        let action_manifest_path =
+            CString::new(action_manifest_path.to_string_lossy().as_bytes()).unwrap();
+
+        let result =
+            unsafe { self.0.SetActionManifestPath.unwrap()(action_manifest_path.as_ptr() as _) };
+        if result == EVRInputError_VRInputError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Returns a handle for an action set. This handle is used for all performance-sensitive calls. */
+    pub fn action_set_handle(
+        &self,
+        action_set_name: &str,
+    ) -> Result<ActionSetHandle, VRInputError> {
+        let mut handle: VRActionSetHandle_t = k_ulInvalidActionSetHandle;
+        let action_set_name = CString::new(action_set_name).unwrap();
+
+        let result = unsafe {
+            self.0.GetActionSetHandle.unwrap()(action_set_name.as_ptr() as _, &mut handle)
+        };
+        if result == EVRInputError_VRInputError_None {
+            Ok(ActionSetHandle(handle))
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Returns a handle for an action. This handle is used for all performance-sensitive calls. */
+    pub fn action_handle(&self, action_name: &str) -> Result<ActionHandle, VRInputError> {
+        let mut handle: VRActionHandle_t = k_ulInvalidActionHandle;
+        let action_name = CString::new(action_name).unwrap();
+
+        let result =
+            unsafe { self.0.GetActionHandle.unwrap()(action_name.as_ptr() as _, &mut handle) };
+        if result == EVRInputError_VRInputError_None {
+            Ok(ActionHandle(handle))
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Returns a handle for any path in the input system. E.g. /user/hand/right */
+    pub fn input_source_handle(
+        &self,
+        input_source_path: &str,
+    ) -> Result<InputSourceHandle, VRInputError> {
+        let mut handle: VRInputValueHandle_t = k_ulInvalidInputValueHandle;
+        let input_source_path = CString::new(input_source_path).unwrap();
+
+        let result = unsafe {
+            self.0.GetInputSourceHandle.unwrap()(input_source_path.as_ptr() as _, &mut handle)
+        };
+        if result == EVRInputError_VRInputError_None {
+            Ok(InputSourceHandle(handle))
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Reads the current state into all actions. After this call, the results of Get*Action calls
+     * will be the same until the next call to UpdateActionState. */
+    pub fn update_action_state(&self, sets: &[ActiveActionSet]) -> Result<(), VRInputError> {
+        let result = unsafe {
+            self.0.UpdateActionState.unwrap()(
+                sets.as_ptr() as *mut _,
+                std::mem::size_of::<VRActiveActionSet_t>() as u32,
+                sets.len() as u32,
+            )
+        };
+        if result == EVRInputError_VRInputError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Reads the state of a digital action given its handle. This will return VRInputError_WrongType if the type of
+     * action is something other than digital */
+    pub fn digital_action_data(
+        &self,
+        action: ActionHandle,
+        restrict_to: Option<InputSourceHandle>,
+    ) -> Result<DigitalActionData, VRInputError> {
+        let mut data = MaybeUninit::<InputDigitalActionData_t>::uninit();
+        let restrict_to = restrict_to.unwrap_or(InputSourceHandle::INVALID);
+
+        let result = unsafe {
+            self.0.GetDigitalActionData.unwrap()(
+                action.0,
+                data.as_mut_ptr(),
+                std::mem::size_of::<InputDigitalActionData_t>() as u32,
+                restrict_to.0,
+            )
+        };
+        if result == EVRInputError_VRInputError_None {
+            Ok(unsafe { data.assume_init() }.into())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Reads the state of an analog action given its handle. This will return VRInputError_WrongType if the type of
+     * action is something other than analog */
+    pub fn analog_action_data(
+        &self,
+        action: ActionHandle,
+        restrict_to: Option<InputSourceHandle>,
+    ) -> Result<AnalogActionData, VRInputError> {
+        let mut data = MaybeUninit::<InputAnalogActionData_t>::uninit();
+        let restrict_to = restrict_to.unwrap_or(InputSourceHandle::INVALID);
+
+        let result = unsafe {
+            self.0.GetAnalogActionData.unwrap()(
+                action.0,
+                data.as_mut_ptr(),
+                std::mem::size_of::<InputAnalogActionData_t>() as u32,
+                restrict_to.0,
+            )
+        };
+        if result == EVRInputError_VRInputError_None {
+            Ok(unsafe { data.assume_init() }.into())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Reads the state of a pose action given its handle for the number of seconds relative to now. This
+     * will generally be called with negative times from the fUpdateTime fields in other actions. */
+    pub fn pose_action_data_relative_to_now(
+        &self,
+        action: ActionHandle,
+        origin: TrackingUniverseOrigin,
+        predicted_seconds_from_now: f32,
+        restrict_to: Option<InputSourceHandle>,
+    ) -> Result<PoseActionData, VRInputError> {
+        let mut data = MaybeUninit::<InputPoseActionData_t>::uninit();
+        let restrict_to = restrict_to.unwrap_or(InputSourceHandle::INVALID);
+
+        let result = unsafe {
+            self.0.GetPoseActionDataRelativeToNow.unwrap()(
+                action.0,
+                origin as ETrackingUniverseOrigin,
+                predicted_seconds_from_now,
+                data.as_mut_ptr(),
+                std::mem::size_of::<InputPoseActionData_t>() as u32,
+                restrict_to.0,
+            )
+        };
+        if result == EVRInputError_VRInputError_None {
+            Ok(unsafe { data.assume_init() }.into())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Reads the state of a pose action given its handle. The returned values will match the values returned
+     * by the last call to IVRCompositor::WaitGetPoses(). */
+    pub fn pose_action_data_for_next_frame(
+        &self,
+        action: ActionHandle,
+        origin: TrackingUniverseOrigin,
+        restrict_to: Option<InputSourceHandle>,
+    ) -> Result<PoseActionData, VRInputError> {
+        let mut data = MaybeUninit::<InputPoseActionData_t>::uninit();
+        let restrict_to = restrict_to.unwrap_or(InputSourceHandle::INVALID);
+
+        let result = unsafe {
+            self.0.GetPoseActionDataForNextFrame.unwrap()(
+                action.0,
+                origin as ETrackingUniverseOrigin,
+                data.as_mut_ptr(),
+                std::mem::size_of::<InputPoseActionData_t>() as u32,
+                restrict_to.0,
+            )
+        };
+        if result == EVRInputError_VRInputError_None {
+            Ok(unsafe { data.assume_init() }.into())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Reads the state of a skeletal action given its handle. */
+    pub fn skeletal_action_data(
+        &self,
+        action: ActionHandle,
+    ) -> Result<SkeletalActionData, VRInputError> {
+        let mut data = MaybeUninit::<InputSkeletalActionData_t>::uninit();
+
+        let result = unsafe {
+            self.0.GetSkeletalActionData.unwrap()(
+                action.0,
+                data.as_mut_ptr(),
+                std::mem::size_of::<InputSkeletalActionData_t>() as u32,
+            )
+        };
+        if result == EVRInputError_VRInputError_None {
+            Ok(unsafe { data.assume_init() }.into())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Triggers a haptic event as described by the specified action */
+    pub fn trigger_haptic_vibration_action(
+        &self,
+        action: ActionHandle,
+        start_seconds_from_now: f32,
+        duration_seconds: f32,
+        frequency: f32,
+        amplitude: f32,
+        restrict_to: Option<InputSourceHandle>,
+    ) -> Result<(), VRInputError> {
+        let restrict_to = restrict_to.unwrap_or(InputSourceHandle::INVALID);
+
+        let result = unsafe {
+            self.0.TriggerHapticVibrationAction.unwrap()(
+                action.0,
+                start_seconds_from_now,
+                duration_seconds,
+                frequency,
+                amplitude,
+                restrict_to.0,
+            )
+        };
+        if result == EVRInputError_VRInputError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Retrieve origin handles for an action */
+    pub fn action_origins(
+        &self,
+        action_set: ActionSetHandle,
+        action: ActionHandle,
+    ) -> Result<Vec<InputSourceHandle>, VRInputError> {
+        let mut origins = [k_ulInvalidInputValueHandle; k_unMaxActionOriginCount as usize];
+
+        let result = unsafe {
+            self.0.GetActionOrigins.unwrap()(
+                action_set.0,
+                action.0,
+                origins.as_mut_ptr(),
+                origins.len() as u32,
+            )
+        };
+        if result == EVRInputError_VRInputError_None {
+            Ok(origins
+                .iter()
+                .filter(|&&x| x != k_ulInvalidInputValueHandle)
+                .map(|&x| InputSourceHandle(x))
+                .collect())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Retrieves the name of the origin in the current language. unStringSectionsToInclude is a bitfield of values in
+     * EVRInputStringBits that allows the application to specify which parts of the origin's information it wants a
+     * string for. */
+    pub fn origin_localized_name(
+        &self,
+        origin: InputSourceHandle,
+        sections: InputStringBits,
+    ) -> Result<String, VRInputError> {
+        let mut buffer = [0; 256];
+        unsafe {
+            let result = self.0.GetOriginLocalizedName.unwrap()(
+                origin.0,
+                buffer.as_mut_ptr(),
+                buffer.len() as u32,
+                sections,
+            );
+            if result == EVRInputError_VRInputError_None {
+                Ok(CStr::from_ptr(buffer.as_ptr())
+                    .to_string_lossy()
+                    .into_owned())
+            } else {
+                Err(result.into())
+            }
+        }
+    }
+
+    /** Retrieves useful information for the origin of this action */
+    pub fn origin_tracked_device_info(
+        &self,
+        origin: InputSourceHandle,
+    ) -> Result<InputOriginInfo, VRInputError> {
+        let mut info = MaybeUninit::<InputOriginInfo_t>::uninit();
+
+        let result = unsafe {
+            self.0.GetOriginTrackedDeviceInfo.unwrap()(
+                origin.0,
+                info.as_mut_ptr(),
+                std::mem::size_of::<InputOriginInfo_t>() as u32,
+            )
+        };
+        if result == EVRInputError_VRInputError_None {
+            let info = unsafe { info.assume_init() };
+            Ok(InputOriginInfo {
+                device_path: InputSourceHandle(info.devicePath),
+                tracked_device_index: info.trackedDeviceIndex,
+                render_model_component_name: unsafe {
+                    CStr::from_ptr(info.rchRenderModelComponentName.as_ptr())
+                }
+                .to_string_lossy()
+                .into_owned(),
+            })
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Shows the current binding for the action in-headset */
+    pub fn show_action_origins(
+        &self,
+        action_set: ActionSetHandle,
+        action: ActionHandle,
+    ) -> Result<(), VRInputError> {
+        let result = unsafe { self.0.ShowActionOrigins.unwrap()(action_set.0, action.0) };
+        if result == EVRInputError_VRInputError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Shows the current binding all the actions in the specified action sets */
+    pub fn show_bindings_for_action_set(
+        &self,
+        sets: &[ActiveActionSet],
+        origin_to_highlight: Option<InputSourceHandle>,
+    ) -> Result<(), VRInputError> {
+        let origin_to_highlight = origin_to_highlight.unwrap_or(InputSourceHandle::INVALID);
+
+        let result = unsafe {
+            self.0.ShowBindingsForActionSet.unwrap()(
+                sets.as_ptr() as *mut _,
+                std::mem::size_of::<VRActiveActionSet_t>() as u32,
+                sets.len() as u32,
+                origin_to_highlight.0,
+            )
+        };
+        if result == EVRInputError_VRInputError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn active_action_sets_are_priority_ordered() {
+        let sets = ActiveActionSets::new()
+            .add_with_priority(ActionSetHandle(1), 0)
+            .add_with_priority(ActionSetHandle(2), 10)
+            .add(
+                ActiveActionSet::new(ActionSetHandle(3))
+                    .restrict_to_device(InputSourceHandle(7))
+                    .with_secondary_action_set(ActionSetHandle(4))
+                    .with_priority(10),
+            )
+            .add_with_priority(ActionSetHandle(5), -1)
+            .build();
+
+        let order: Vec<_> = sets.iter().map(|x| x.action_set()).collect();
+        assert_eq!(
+            order,
+            vec![
+                ActionSetHandle(2),
+                ActionSetHandle(3),
+                ActionSetHandle(1),
+                ActionSetHandle(5)
+            ]
+        );
+        assert_eq!(sets[1].restricted_to_device(), Some(InputSourceHandle(7)));
+        assert_eq!(sets[1].secondary_action_set(), Some(ActionSetHandle(4)));
+        assert_eq!(sets[0].restricted_to_device(), None);
+        assert_eq!(sets[0].secondary_action_set(), None);
+    }
+}
@@ -0,0 +1,298 @@
+//! Skeletal input, such as the finger tracking of the Valve Index controllers.
+
+use std::ffi::CStr;
+use std::{mem, ptr};
+
+use openvr_sys as sys;
+
+use super::{ActionHandle, VRInputError};
+use crate::Input;
+
+pub type BoneIndex = sys::BoneIndex_t;
+
+/// Position and orientation of a single bone
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct BoneTransform {
+    /// x, y, z, w
+    pub position: [f32; 4],
+    /// w, x, y, z
+    pub orientation: [f32; 4],
+}
+
+impl Default for BoneTransform {
+    fn default() -> Self {
+        BoneTransform {
+            position: [0.0, 0.0, 0.0, 1.0],
+            orientation: [1.0, 0.0, 0.0, 0.0],
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SkeletalTransformSpace {
+    /// Transforms are relative to the origin of the skeleton
+    Model = sys::EVRSkeletalTransformSpace_VRSkeletalTransformSpace_Model as isize,
+    /// Transforms are relative to the parent bone
+    Parent = sys::EVRSkeletalTransformSpace_VRSkeletalTransformSpace_Parent as isize,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SkeletalMotionRange {
+    /// The range of motion of the skeleton takes into account any physical limits imposed by the controller itself.
+    /// This will tend to be the most accurate pose compared to the user's actual hand pose, but might not allow a
+    /// closed fist for example.
+    WithController = sys::EVRSkeletalMotionRange_VRSkeletalMotionRange_WithController as isize,
+    /// Retarget the range of motion provided by the input device to make the hand appear to move as if it was not
+    /// holding a controller. eg: map "hand grasping controller" to "closed fist"
+    WithoutController =
+        sys::EVRSkeletalMotionRange_VRSkeletalMotionRange_WithoutController as isize,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SkeletalReferencePose {
+    BindPose = sys::EVRSkeletalReferencePose_VRSkeletalReferencePose_BindPose as isize,
+    OpenHand = sys::EVRSkeletalReferencePose_VRSkeletalReferencePose_OpenHand as isize,
+    Fist = sys::EVRSkeletalReferencePose_VRSkeletalReferencePose_Fist as isize,
+    GripLimit = sys::EVRSkeletalReferencePose_VRSkeletalReferencePose_GripLimit as isize,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SkeletalTrackingLevel {
+    /// Body part location can't be directly determined by the device. Any skeletal pose provided by the device is
+    /// estimated by assuming the position required to active buttons, triggers, joysticks, or other input sensors.
+    /// E.g. Vive Controller, Gamepad
+    Estimated = sys::EVRSkeletalTrackingLevel_VRSkeletalTracking_Estimated as isize,
+    /// Body part location can be measured directly but with fewer degrees of freedom than the actual body part.
+    /// Certain body part positions may be unmeasured by the device and estimated from other input data.
+    /// E.g. Index Controllers, gloves that only measure finger curl
+    Partial = sys::EVRSkeletalTrackingLevel_VRSkeletalTracking_Partial as isize,
+    /// Body part location can be measured directly throughout the entire range of motion of the body part.
+    /// E.g. Mocap suit for the full body, gloves that measure rotation of each finger segment
+    Full = sys::EVRSkeletalTrackingLevel_VRSkeletalTracking_Full as isize,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SummaryType {
+    /// The data should match the animated transforms in the skeleton transforms. This data will probably be smoothed
+    /// and may be more latent
+    FromAnimation = sys::EVRSummaryType_VRSummaryType_FromAnimation as isize,
+    /// The data should be the unprocessed values from the device when available. This data may include more jitter
+    /// but may be provided with less latency
+    FromDevice = sys::EVRSummaryType_VRSummaryType_FromDevice as isize,
+}
+
+/// Summary of the curl and splay of each finger
+#[derive(Debug, Copy, Clone)]
+pub struct SkeletalSummaryData {
+    /// The amount that each finger is curled inwards towards the palm. In the case of the thumb, this represents how
+    /// much the thumb is wrapped around the fist. 0 means straight, 1 means fully curled
+    pub finger_curl: [f32; 5],
+    /// The amount that each pair of adjacent fingers are separated. 0 means the digits are touching, 1 means they
+    /// are fully separated.
+    pub finger_splay: [f32; 4],
+}
+
+impl Input {
+    /** Reads the number of bones in skeleton associated with the given action */
+    pub fn bone_count(&self, action: ActionHandle) -> Result<u32, VRInputError> {
+        let mut count = 0;
+        let result = unsafe { self.0.GetBoneCount.unwrap()(action.0, &mut count) };
+        if result == sys::EVRInputError_VRInputError_None {
+            Ok(count)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Fills the given array with the index of each bone's parent in the skeleton associated with the given action */
+    pub fn bone_hierarchy(&self, action: ActionHandle) -> Result<Vec<BoneIndex>, VRInputError> {
+        let mut parents = vec![0; self.bone_count(action)? as usize];
+        let result = unsafe {
+            self.0.GetBoneHierarchy.unwrap()(action.0, parents.as_mut_ptr(), parents.len() as u32)
+        };
+        if result == sys::EVRInputError_VRInputError_None {
+            Ok(parents)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Fills the given buffer with the name of the bone at the given index in the skeleton associated with the given
+     * action */
+    pub fn bone_name(&self, action: ActionHandle, bone: BoneIndex) -> Result<String, VRInputError> {
+        let mut buffer = [0; sys::k_unMaxBoneNameLength as usize];
+        unsafe {
+            let result = self.0.GetBoneName.unwrap()(
+                action.0,
+                bone,
+                buffer.as_mut_ptr(),
+                buffer.len() as u32,
+            );
+            if result == sys::EVRInputError_VRInputError_None {
+                Ok(CStr::from_ptr(buffer.as_ptr())
+                    .to_string_lossy()
+                    .into_owned())
+            } else {
+                Err(result.into())
+            }
+        }
+    }
+
+    /** Fills the given buffer with the transforms for a specific static skeletal reference pose */
+    pub fn skeletal_reference_transforms(
+        &self,
+        action: ActionHandle,
+        space: SkeletalTransformSpace,
+        pose: SkeletalReferencePose,
+        transforms: &mut [BoneTransform],
+    ) -> Result<(), VRInputError> {
+        let result = unsafe {
+            self.0.GetSkeletalReferenceTransforms.unwrap()(
+                action.0,
+                space as sys::EVRSkeletalTransformSpace,
+                pose as sys::EVRSkeletalReferencePose,
+                transforms.as_mut_ptr() as *mut _,
+                transforms.len() as u32,
+            )
+        };
+        if result == sys::EVRInputError_VRInputError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Reads the level of accuracy to which the controller is able to track the user to recreate a skeletal pose */
+    pub fn skeletal_tracking_level(
+        &self,
+        action: ActionHandle,
+    ) -> Result<SkeletalTrackingLevel, VRInputError> {
+        let mut level = sys::EVRSkeletalTrackingLevel_VRSkeletalTracking_Estimated;
+        let result = unsafe { self.0.GetSkeletalTrackingLevel.unwrap()(action.0, &mut level) };
+        if result != sys::EVRInputError_VRInputError_None {
+            return Err(result.into());
+        }
+        Ok(match level {
+            sys::EVRSkeletalTrackingLevel_VRSkeletalTracking_Partial => {
+                SkeletalTrackingLevel::Partial
+            }
+            sys::EVRSkeletalTrackingLevel_VRSkeletalTracking_Full => SkeletalTrackingLevel::Full,
+            _ => SkeletalTrackingLevel::Estimated,
+        })
+    }
+
+    /** Reads the state of the skeletal bone data associated with this action and copies it into the given buffer. */
+    pub fn skeletal_bone_data(
+        &self,
+        action: ActionHandle,
+        space: SkeletalTransformSpace,
+        motion_range: SkeletalMotionRange,
+        transforms: &mut [BoneTransform],
+    ) -> Result<(), VRInputError> {
+        let result = unsafe {
+            self.0.GetSkeletalBoneData.unwrap()(
+                action.0,
+                space as sys::EVRSkeletalTransformSpace,
+                motion_range as sys::EVRSkeletalMotionRange,
+                transforms.as_mut_ptr() as *mut _,
+                transforms.len() as u32,
+            )
+        };
+        if result == sys::EVRInputError_VRInputError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Reads summary information about the current pose of the skeleton associated with the given action. */
+    pub fn skeletal_summary_data(
+        &self,
+        action: ActionHandle,
+        summary_type: SummaryType,
+    ) -> Result<SkeletalSummaryData, VRInputError> {
+        let mut data = mem::MaybeUninit::<sys::VRSkeletalSummaryData_t>::uninit();
+        let result = unsafe {
+            self.0.GetSkeletalSummaryData.unwrap()(
+                action.0,
+                summary_type as sys::EVRSummaryType,
+                data.as_mut_ptr(),
+            )
+        };
+        if result == sys::EVRInputError_VRInputError_None {
+            let data = unsafe { data.assume_init() };
+            Ok(SkeletalSummaryData {
+                finger_curl: data.flFingerCurl,
+                finger_splay: data.flFingerSplay,
+            })
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Reads the state of the skeletal bone data in a compressed form that is suitable for
+     * sending over the network. The required buffer size will never exceed ( sizeof(VR_BoneTransform_t)*boneCount + 2).
+     * Usually the size will be much smaller. */
+    pub fn skeletal_bone_data_compressed(
+        &self,
+        action: ActionHandle,
+        motion_range: SkeletalMotionRange,
+    ) -> Result<Vec<u8>, VRInputError> {
+        let mut required = 0;
+        let result = unsafe {
+            self.0.GetSkeletalBoneDataCompressed.unwrap()(
+                action.0,
+                motion_range as sys::EVRSkeletalMotionRange,
+                ptr::null_mut(),
+                0,
+                &mut required,
+            )
+        };
+        if result != sys::EVRInputError_VRInputError_None
+            && result != sys::EVRInputError_VRInputError_BufferTooSmall
+        {
+            return Err(result.into());
+        }
+
+        let mut buffer = vec![0u8; required as usize];
+        let result = unsafe {
+            self.0.GetSkeletalBoneDataCompressed.unwrap()(
+                action.0,
+                motion_range as sys::EVRSkeletalMotionRange,
+                buffer.as_mut_ptr() as *mut _,
+                buffer.len() as u32,
+                &mut required,
+            )
+        };
+        if result == sys::EVRInputError_VRInputError_None {
+            buffer.truncate(required as usize);
+            Ok(buffer)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Turns a compressed buffer from GetSkeletalBoneDataCompressed and turns it back into a bone transform array. */
+    pub fn decompress_skeletal_bone_data(
+        &self,
+        compressed: &[u8],
+        space: SkeletalTransformSpace,
+        transforms: &mut [BoneTransform],
+    ) -> Result<(), VRInputError> {
+        let result = unsafe {
+            self.0.DecompressSkeletalBoneData.unwrap()(
+                compressed.as_ptr() as *mut _,
+                compressed.len() as u32,
+                space as sys::EVRSkeletalTransformSpace,
+                transforms.as_mut_ptr() as *mut _,
+                transforms.len() as u32,
+            )
+        };
+        if result == sys::EVRInputError_VRInputError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+}
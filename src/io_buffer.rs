@@ -0,0 +1,142 @@
+//! Shared memory buffers published by drivers, addressed by path.
+
+use std::{error::Error, ffi::CString, fmt::Display, mem};
+
+use bytemuck::Pod;
+use openvr_sys as sys;
+use openvr_sys::{EIOBufferError, EIOBufferError_IOBuffer_Success, IOBufferHandle_t};
+
+use crate::system::event::PropertyContainerHandle;
+use crate::IOBuffer;
+
+#[derive(Debug)]
+pub struct IOBufferError(pub EIOBufferError);
+impl Display for IOBufferError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EIOBufferError {}", self.0)
+    }
+}
+impl Error for IOBufferError {}
+impl From<EIOBufferError> for IOBufferError {
+    fn from(err: EIOBufferError) -> Self {
+        IOBufferError(err)
+    }
+}
+
+pub type IOBufferMode = u32;
+
+pub mod io_buffer_mode {
+    use super::*;
+    pub const READ: IOBufferMode = sys::EIOBufferMode_IOBufferMode_Read as IOBufferMode;
+    pub const WRITE: IOBufferMode = sys::EIOBufferMode_IOBufferMode_Write as IOBufferMode;
+    /** Creates the buffer if it doesn't exist yet */
+    pub const CREATE: IOBufferMode = sys::EIOBufferMode_IOBufferMode_Create as IOBufferMode;
+}
+
+impl IOBuffer {
+    /** opens an existing or creates a new IOBuffer of unSize bytes
+     *
+     * The buffer is closed when the returned handle is dropped. */
+    pub fn open(
+        &self,
+        path: &str,
+        mode: IOBufferMode,
+        element_size: u32,
+        elements: u32,
+    ) -> Result<IoBufferHandle<'_>, IOBufferError> {
+        let path = CString::new(path).unwrap();
+        let mut handle = sys::k_ulInvalidIOBufferHandle;
+        let result = unsafe {
+            self.0.Open.unwrap()(
+                path.as_ptr() as _,
+                mode as sys::EIOBufferMode,
+                element_size,
+                elements,
+                &mut handle,
+            )
+        };
+        if result == EIOBufferError_IOBuffer_Success {
+            Ok(IoBufferHandle {
+                io_buffer: self,
+                handle,
+            })
+        } else {
+            Err(result.into())
+        }
+    }
+}
+
+/// An open IO buffer, closed when dropped.
+pub struct IoBufferHandle<'a> {
+    io_buffer: &'a IOBuffer,
+    handle: IOBufferHandle_t,
+}
+
+impl<'a> IoBufferHandle<'a> {
+    pub fn raw(&self) -> IOBufferHandle_t {
+        self.handle
+    }
+
+    /** reads up to unBytes from buffer into *pDst, returning number of bytes read in *punRead */
+    pub fn read(&self, dst: &mut [u8]) -> Result<usize, IOBufferError> {
+        let mut read = 0;
+        let result = unsafe {
+            self.io_buffer.0.Read.unwrap()(
+                self.handle,
+                dst.as_mut_ptr() as *mut _,
+                dst.len() as u32,
+                &mut read,
+            )
+        };
+        if result == EIOBufferError_IOBuffer_Success {
+            Ok(read as usize)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /// Reads records into `dst`, returning the number of whole records read and the number of bytes of a trailing
+    /// partial record.
+    ///
+    /// Only `dst[..records]` holds data from this read; the partial record's bytes are at the start of
+    /// `dst[records]`, and the rest of `dst` is unchanged. Partial records only happen if `T` doesn't match the
+    /// element size the buffer was opened with. Fails with `IOBuffer_InvalidArgument` if `T` is zero-sized.
+    pub fn read_elements<T: Pod>(&self, dst: &mut [T]) -> Result<(usize, usize), IOBufferError> {
+        let size = mem::size_of::<T>();
+        if size == 0 {
+            return Err(sys::EIOBufferError_IOBuffer_InvalidArgument.into());
+        }
+        let read = self.read(bytemuck::cast_slice_mut(dst))?;
+        Ok((read / size, read % size))
+    }
+
+    /** writes unBytes of data from *pSrc into a buffer. */
+    pub fn write(&self, src: &[u8]) -> Result<(), IOBufferError> {
+        let result = unsafe {
+            self.io_buffer.0.Write.unwrap()(self.handle, src.as_ptr() as *mut _, src.len() as u32)
+        };
+        if result == EIOBufferError_IOBuffer_Success {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** retrieves the property container of an buffer. */
+    pub fn property_container(&self) -> PropertyContainerHandle {
+        unsafe { self.io_buffer.0.PropertyContainer.unwrap()(self.handle) }
+    }
+
+    /** inexpensive call to determine if there are readers of the buffer */
+    pub fn has_readers(&self) -> bool {
+        unsafe { self.io_buffer.0.HasReaders.unwrap()(self.handle) }
+    }
+}
+
+impl<'a> Drop for IoBufferHandle<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            self.io_buffer.0.Close.unwrap()(self.handle);
+        }
+    }
+}
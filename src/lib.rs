@@ -18,6 +18,14 @@ pub mod system;
 pub mod applications;
 pub mod overlay;
 pub mod notifications;
+pub mod input;
+pub mod settings;
+pub mod screenshots;
+pub mod spatial_anchors;
+pub mod tracked_camera;
+pub mod io_buffer;
+pub mod driver_manager;
+pub mod extended_display;
 
 pub use crate::tracking::*;
 
@@ -0,0 +1,298 @@
+use std::collections::HashMap;
+
+use openvr_sys::{VRNotificationId, VROverlayHandle_t};
+
+use super::{NotificationBitmap, NotificationStyle, NotificationType, VRNotificationError};
+use crate::system::Event;
+use crate::Notifications;
+
+/// Where a notification is in its lifecycle, as reported by the `Notification_*` events
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NotificationStatus {
+    /// Created and waiting in the queue
+    Queued,
+    Shown,
+    Hidden,
+    /// The user started interacting with the notification
+    Interacting,
+    /// OpenVR destroyed the notification; it no longer needs to be removed
+    Destroyed,
+}
+
+struct Entry<T> {
+    status: NotificationStatus,
+    user_value: u64,
+    payload: T,
+}
+
+/// The statuses and payloads of a set of notifications, updated from events.
+///
+/// This only does the bookkeeping for `NotificationManager`, and never calls into OpenVR.
+///
+/// Events are matched to notifications by their notification ID alone. The user value in an event is only what was
+/// passed to `Notifications::create`, which applications commonly reuse across notifications, so it can't tell apart
+/// two notifications with the same value. Use `find_by_user_value` to look notifications up by it instead.
+pub struct NotificationTracker<T> {
+    entries: HashMap<VRNotificationId, Entry<T>>,
+}
+
+impl<T> NotificationTracker<T> {
+    pub fn new() -> Self {
+        NotificationTracker {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Starts tracking a newly created notification, replacing any earlier one with the same ID.
+    pub fn insert(&mut self, id: VRNotificationId, user_value: u64, payload: T) {
+        self.entries.insert(
+            id,
+            Entry {
+                status: NotificationStatus::Queued,
+                user_value,
+                payload,
+            },
+        );
+    }
+
+    pub fn status(&self, id: VRNotificationId) -> Option<NotificationStatus> {
+        self.entries.get(&id).map(|x| x.status)
+    }
+
+    pub fn user_value(&self, id: VRNotificationId) -> Option<u64> {
+        self.entries.get(&id).map(|x| x.user_value)
+    }
+
+    pub fn payload(&self, id: VRNotificationId) -> Option<&T> {
+        self.entries.get(&id).map(|x| &x.payload)
+    }
+
+    pub fn payload_mut(&mut self, id: VRNotificationId) -> Option<&mut T> {
+        self.entries.get_mut(&id).map(|x| &mut x.payload)
+    }
+
+    /// Iterates over all tracked notifications, including destroyed ones that have not been removed yet.
+    pub fn iter(&self) -> impl Iterator<Item = (VRNotificationId, NotificationStatus, &T)> {
+        self.entries
+            .iter()
+            .map(|(&id, x)| (id, x.status, &x.payload))
+    }
+
+    /// The IDs of the tracked notifications created with `user_value`, in no particular order.
+    pub fn find_by_user_value(
+        &self,
+        user_value: u64,
+    ) -> impl Iterator<Item = VRNotificationId> + '_ {
+        self.entries
+            .iter()
+            .filter(move |(_, x)| x.user_value == user_value)
+            .map(|(&id, _)| id)
+    }
+
+    /// Updates the status of the notification an event refers to, returning its ID and new status.
+    ///
+    /// Events for notifications that aren't tracked are ignored.
+    pub fn handle_event(
+        &mut self,
+        event: &Event,
+    ) -> Option<(VRNotificationId, NotificationStatus)> {
+        let (data, status) = match *event {
+            Event::Notification_Shown(x) => (x, NotificationStatus::Shown),
+            Event::Notification_Hidden(x) => (x, NotificationStatus::Hidden),
+            Event::Notification_BeginInteraction(x) => (x, NotificationStatus::Interacting),
+            Event::Notification_Destroyed(x) => (x, NotificationStatus::Destroyed),
+            _ => return None,
+        };
+        let entry = self.entries.get_mut(&data.notification_id)?;
+        entry.status = status;
+        Some((data.notification_id, status))
+    }
+
+    /// Stops tracking a notification, returning its payload.
+    pub fn remove(&mut self, id: VRNotificationId) -> Option<T> {
+        self.entries.remove(&id).map(|x| x.payload)
+    }
+
+    /// Stops tracking all destroyed notifications, returning their payloads.
+    pub fn take_destroyed(&mut self) -> Vec<(VRNotificationId, T)> {
+        let ids: Vec<_> = self
+            .entries
+            .iter()
+            .filter(|(_, x)| x.status == NotificationStatus::Destroyed)
+            .map(|(&id, _)| id)
+            .collect();
+        ids.into_iter()
+            .map(|id| (id, self.entries.remove(&id).unwrap().payload))
+            .collect()
+    }
+}
+
+impl<T> Default for NotificationTracker<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Tracks the notifications created through it, each with a payload of type `T`.
+///
+/// Pass every event from `System::poll_next_event` to `NotificationManager::handle_event` to keep the statuses up to
+/// date. Notifications that have not been destroyed by OpenVR are removed when the manager is dropped.
+pub struct NotificationManager<'a, T> {
+    notifications: &'a Notifications,
+    tracker: NotificationTracker<T>,
+}
+
+impl<'a, T> NotificationManager<'a, T> {
+    pub fn new(notifications: &'a Notifications) -> Self {
+        NotificationManager {
+            notifications,
+            tracker: NotificationTracker::new(),
+        }
+    }
+
+    /// Creates a notification with `Notifications::create` and starts tracking it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        &mut self,
+        overlay_handle: VROverlayHandle_t,
+        user_value: u64,
+        notification_type: NotificationType,
+        text: &str,
+        style: NotificationStyle,
+        image: Option<&NotificationBitmap>,
+        payload: T,
+    ) -> Result<VRNotificationId, VRNotificationError> {
+        let id = self.notifications.create(
+            overlay_handle,
+            user_value,
+            notification_type,
+            text,
+            style,
+            image,
+        )?;
+        self.tracker.insert(id, user_value, payload);
+        Ok(id)
+    }
+
+    pub fn status(&self, id: VRNotificationId) -> Option<NotificationStatus> {
+        self.tracker.status(id)
+    }
+
+    pub fn user_value(&self, id: VRNotificationId) -> Option<u64> {
+        self.tracker.user_value(id)
+    }
+
+    pub fn payload(&self, id: VRNotificationId) -> Option<&T> {
+        self.tracker.payload(id)
+    }
+
+    pub fn payload_mut(&mut self, id: VRNotificationId) -> Option<&mut T> {
+        self.tracker.payload_mut(id)
+    }
+
+    /// Iterates over all tracked notifications, including destroyed ones that have not been removed yet.
+    pub fn iter(&self) -> impl Iterator<Item = (VRNotificationId, NotificationStatus, &T)> {
+        self.tracker.iter()
+    }
+
+    /// See `NotificationTracker::find_by_user_value`.
+    pub fn find_by_user_value(
+        &self,
+        user_value: u64,
+    ) -> impl Iterator<Item = VRNotificationId> + '_ {
+        self.tracker.find_by_user_value(user_value)
+    }
+
+    /// See `NotificationTracker::handle_event`.
+    pub fn handle_event(
+        &mut self,
+        event: &Event,
+    ) -> Option<(VRNotificationId, NotificationStatus)> {
+        self.tracker.handle_event(event)
+    }
+
+    /// Removes a notification, hiding it first if it is shown, and stops tracking it.
+    ///
+    /// Returns `Ok(None)` if the notification isn't tracked.
+    pub fn remove(&mut self, id: VRNotificationId) -> Result<Option<T>, VRNotificationError> {
+        match self.tracker.status(id) {
+            None => return Ok(None),
+            Some(NotificationStatus::Destroyed) => {}
+            Some(_) => self.notifications.remove(id)?,
+        }
+        Ok(self.tracker.remove(id))
+    }
+
+    /// Stops tracking all destroyed notifications, returning their payloads.
+    pub fn take_destroyed(&mut self) -> Vec<(VRNotificationId, T)> {
+        self.tracker.take_destroyed()
+    }
+}
+
+impl<'a, T> Drop for NotificationManager<'a, T> {
+    fn drop(&mut self) {
+        for (id, status, _) in self.tracker.iter() {
+            if status != NotificationStatus::Destroyed {
+                let _ = self.notifications.remove(id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::system::event;
+
+    #[test]
+    fn events_update_status() {
+        let mut tracker = NotificationTracker::new();
+        tracker.insert(1, 10, 100);
+        tracker.insert(2, 20, 200);
+        tracker.insert(3, 10, 300);
+        let data = |notification_id, user_value| event::Notification {
+            user_value,
+            notification_id,
+        };
+
+        assert_eq!(
+            tracker.handle_event(&Event::Notification_Shown(data(1, 10))),
+            Some((1, NotificationStatus::Shown))
+        );
+        assert_eq!(
+            tracker.handle_event(&Event::Notification_BeginInteraction(data(2, 20))),
+            Some((2, NotificationStatus::Interacting))
+        );
+        // Only the ID identifies a notification; user values are often shared
+        assert_eq!(
+            tracker.handle_event(&Event::Notification_Hidden(data(4, 10))),
+            None
+        );
+        assert_eq!(tracker.status(1), Some(NotificationStatus::Shown));
+        assert_eq!(tracker.status(3), Some(NotificationStatus::Queued));
+        let mut ids: Vec<_> = tracker.find_by_user_value(10).collect();
+        ids.sort();
+        assert_eq!(ids, vec![1, 3]);
+        assert_eq!(tracker.user_value(2), Some(20));
+        assert_eq!(tracker.handle_event(&Event::IpdChanged), None);
+        assert_eq!(
+            tracker.handle_event(&Event::Notification_Destroyed(data(1, 10))),
+            Some((1, NotificationStatus::Destroyed))
+        );
+        assert_eq!(tracker.status(2), Some(NotificationStatus::Interacting));
+        assert_eq!(tracker.payload(2), Some(&200));
+
+        assert_eq!(tracker.take_destroyed(), vec![(1, 100)]);
+        assert_eq!(tracker.status(1), None);
+
+        // A late event for a notification that is no longer tracked
+        assert_eq!(
+            tracker.handle_event(&Event::Notification_Hidden(data(1, 10))),
+            None
+        );
+        assert_eq!(tracker.find_by_user_value(10).collect::<Vec<_>>(), vec![3]);
+        assert_eq!(tracker.remove(2), Some(200));
+        assert_eq!(tracker.remove(3), Some(300));
+        assert_eq!(tracker.iter().count(), 0);
+    }
+}
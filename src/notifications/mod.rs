@@ -0,0 +1,159 @@
+use std::{error::Error, ffi::CString, fmt::Display, marker::PhantomData};
+
+use openvr_sys as sys;
+use openvr_sys::{
+    EVRNotificationError, EVRNotificationError_VRNotificationError_OK, NotificationBitmap_t,
+    VRNotificationId, VROverlayHandle_t,
+};
+
+use crate::Notifications;
+
+pub mod manager;
+
+pub use self::manager::{NotificationManager, NotificationStatus, NotificationTracker};
+
+#[derive(Debug)]
+pub struct VRNotificationError(pub EVRNotificationError);
+impl Display for VRNotificationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EVRNotificationError {}", self.0)
+    }
+}
+impl Error for VRNotificationError {}
+impl From<EVRNotificationError> for VRNotificationError {
+    fn from(err: EVRNotificationError) -> Self {
+        VRNotificationError(err)
+    }
+}
+
+/** Be aware that the notification type is used as 'priority' to pick the next notification */
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NotificationType {
+    /** Transient notifications are automatically hidden after a period of time set by the user.
+     * They are used for things like information and chat messages that do not require user interaction. */
+    Transient = sys::EVRNotificationType_EVRNotificationType_Transient as isize,
+    /** Persistent notifications are shown to the user until they are hidden by calling RemoveNotification().
+     * They are used for things like phone calls and alarms that require user interaction. */
+    Persistent = sys::EVRNotificationType_EVRNotificationType_Persistent as isize,
+    /** System notifications are shown no matter what. It is expected, that the ulUserValue is used as ID.
+     * If there is already a system notification in the queue with that ID it is not accepted into the queue
+     * to prevent spamming with system notification */
+    TransientSystemWithUserValue =
+        sys::EVRNotificationType_EVRNotificationType_Transient_SystemWithUserValue as isize,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NotificationStyle {
+    /** Creates a notification with minimal external styling. */
+    None = sys::EVRNotificationStyle_EVRNotificationStyle_None as isize,
+    /** Used for notifications about overlay-level status. In Steam this is used for events like downloads completing. */
+    Application = sys::EVRNotificationStyle_EVRNotificationStyle_Application as isize,
+    /** Used for notifications about contacts that are unknown or not available. In Steam this is used for friend
+     * invitations and offline friends. */
+    ContactDisabled = sys::EVRNotificationStyle_EVRNotificationStyle_Contact_Disabled as isize,
+    /** Used for notifications about contacts that are available but inactive. In Steam this is used for friends that
+     * are online but not playing a game. */
+    ContactEnabled = sys::EVRNotificationStyle_EVRNotificationStyle_Contact_Enabled as isize,
+    /** Used for notifications about contacts that are available and active. In Steam this is used for friends that
+     * are online and currently running a game. */
+    ContactActive = sys::EVRNotificationStyle_EVRNotificationStyle_Contact_Active as isize,
+}
+
+/// An RGBA8 image to show in a notification, borrowed for as long as the notification is being created.
+#[derive(Debug, Copy, Clone)]
+pub struct NotificationBitmap<'a> {
+    bitmap: NotificationBitmap_t,
+    _data: PhantomData<&'a [u8]>,
+}
+
+impl<'a> NotificationBitmap<'a> {
+    /// Returns `None` unless `rgba` is exactly `width * height * 4` bytes.
+    pub fn new(rgba: &'a [u8], width: u32, height: u32) -> Option<Self> {
+        if width > i32::MAX as u32
+            || height > i32::MAX as u32
+            || rgba.len() as u64 != width as u64 * height as u64 * 4
+        {
+            return None;
+        }
+        Some(NotificationBitmap {
+            bitmap: NotificationBitmap_t {
+                m_pImageData: rgba.as_ptr() as *mut _,
+                m_nWidth: width as i32,
+                m_nHeight: height as i32,
+                m_nBytesPerPixel: 4,
+            },
+            _data: PhantomData,
+        })
+    }
+
+    pub fn width(&self) -> u32 {
+        self.bitmap.m_nWidth as u32
+    }
+
+    pub fn height(&self) -> u32 {
+        self.bitmap.m_nHeight as u32
+    }
+}
+
+impl Notifications {
+    /** Create a notification and enqueue it to be shown to the user.
+     * An overlay handle is required to create a notification, as otherwise it would be impossible for a user to act on it.
+     * To create a two-line notification, use a line break ('\n') to split the text into two lines.
+     * The image argument may be None, in which case the specified overlay's icon will be used instead. */
+    pub fn create(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        user_value: u64,
+        notification_type: NotificationType,
+        text: &str,
+        style: NotificationStyle,
+        image: Option<&NotificationBitmap>,
+    ) -> Result<VRNotificationId, VRNotificationError> {
+        let mut id = 0;
+        let text = CString::new(text).unwrap();
+        let image = image
+            .map(|x| &x.bitmap as *const NotificationBitmap_t as *mut NotificationBitmap_t)
+            .unwrap_or(std::ptr::null_mut());
+        let result = unsafe {
+            self.0.CreateNotification.unwrap()(
+                overlay_handle,
+                user_value,
+                notification_type as sys::EVRNotificationType,
+                text.as_ptr() as _,
+                style as sys::EVRNotificationStyle,
+                image,
+                &mut id,
+            )
+        };
+        if result == EVRNotificationError_VRNotificationError_OK {
+            Ok(id)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Destroy a notification, hiding it first if it currently shown to the user. */
+    pub fn remove(&self, id: VRNotificationId) -> Result<(), VRNotificationError> {
+        let result = unsafe { self.0.RemoveNotification.unwrap()(id) };
+        if result == EVRNotificationError_VRNotificationError_OK {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn notification_bitmap_size() {
+        let rgba = [0u8; 2 * 3 * 4];
+        let bitmap = NotificationBitmap::new(&rgba, 2, 3).unwrap();
+        assert_eq!((bitmap.width(), bitmap.height()), (2, 3));
+        assert!(NotificationBitmap::new(&rgba, 3, 3).is_none());
+        assert!(NotificationBitmap::new(&rgba[1..], 2, 3).is_none());
+        assert!(NotificationBitmap::new(&[], 0, 0).is_some());
+    }
+}
@@ -0,0 +1,169 @@
+//! Dashboard overlays, which appear as tabs in the SteamVR dashboard.
+
+use std::ffi::CString;
+
+use openvr_sys::{EVROverlayError_VROverlayError_None, VROverlayHandle_t};
+
+use super::{OverlayHandle, VROverlayError};
+use crate::system::Event;
+use crate::{Overlay, TrackedDeviceIndex};
+
+impl Overlay {
+    /** Creates a dashboard overlay and returns its handle, followed by the raw handle of its thumbnail overlay.
+     *
+     * The thumbnail belongs to the main overlay and is destroyed along with it, so it is not returned as an owning
+     * `OverlayHandle`. Use the `Overlay` methods to set it up, and don't use it after the main handle is dropped. */
+    pub fn create_dashboard_overlay(
+        &self,
+        overlay_key: &str,
+        overlay_friendly_name: &str,
+    ) -> Result<(OverlayHandle<'_>, VROverlayHandle_t), VROverlayError> {
+        let mut main_handle: VROverlayHandle_t = 0;
+        let mut thumbnail_handle: VROverlayHandle_t = 0;
+        let overlay_key = CString::new(overlay_key).unwrap();
+        let overlay_friendly_name = CString::new(overlay_friendly_name).unwrap();
+
+        let result = unsafe {
+            self.0.CreateDashboardOverlay.unwrap()(
+                overlay_key.as_ptr() as _,
+                overlay_friendly_name.as_ptr() as _,
+                &mut main_handle,
+                &mut thumbnail_handle,
+            )
+        };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok((
+                unsafe { OverlayHandle::from_raw(self, main_handle) },
+                thumbnail_handle,
+            ))
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Returns true if the dashboard is visible */
+    pub fn is_dashboard_visible(&self) -> bool {
+        unsafe { self.0.IsDashboardVisible.unwrap()() }
+    }
+
+    /** returns true if the dashboard is visible and the specified overlay is the active system Overlay */
+    pub fn is_active_dashboard_overlay(&self, overlay_handle: VROverlayHandle_t) -> bool {
+        unsafe { self.0.IsActiveDashboardOverlay.unwrap()(overlay_handle) }
+    }
+
+    /** Sets the dashboard overlay to only appear when the specified process ID has scene focus */
+    pub fn set_dashboard_overlay_scene_process(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        process_id: u32,
+    ) -> Result<(), VROverlayError> {
+        let result =
+            unsafe { self.0.SetDashboardOverlaySceneProcess.unwrap()(overlay_handle, process_id) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Gets the process ID that this dashboard overlay requires to have scene focus */
+    pub fn dashboard_overlay_scene_process(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+    ) -> Result<u32, VROverlayError> {
+        let mut process_id = 0;
+        let result = unsafe {
+            self.0.GetDashboardOverlaySceneProcess.unwrap()(overlay_handle, &mut process_id)
+        };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(process_id)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Shows the dashboard. */
+    pub fn show_dashboard(&self, overlay_to_show: &str) {
+        let overlay_to_show = CString::new(overlay_to_show).unwrap();
+        unsafe { self.0.ShowDashboard.unwrap()(overlay_to_show.as_ptr() as _) }
+    }
+
+    /** Returns the tracked device that has the laser pointer in the dashboard */
+    pub fn get_primary_dashboard_device(&self) -> Option<TrackedDeviceIndex> {
+        let device = unsafe { self.0.GetPrimaryDashboardDevice.unwrap()() };
+        if device == crate::tracked_device_index::INVALID {
+            None
+        } else {
+            Some(device)
+        }
+    }
+}
+
+impl<'a> OverlayHandle<'a> {
+    pub fn is_active_dashboard_overlay(&self) -> bool {
+        self.overlay.is_active_dashboard_overlay(self.handle)
+    }
+
+    pub fn set_dashboard_overlay_scene_process(
+        &self,
+        process_id: u32,
+    ) -> Result<(), VROverlayError> {
+        self.overlay
+            .set_dashboard_overlay_scene_process(self.handle, process_id)
+    }
+
+    pub fn dashboard_overlay_scene_process(&self) -> Result<u32, VROverlayError> {
+        self.overlay.dashboard_overlay_scene_process(self.handle)
+    }
+}
+
+/// Whether the dashboard is open, kept up to date from the event stream.
+///
+/// Query the initial state with `DashboardState::new` and pass every event from `System::poll_next_event` to
+/// `DashboardState::handle_event`, instead of calling `Overlay::is_dashboard_visible` every frame.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct DashboardState {
+    visible: bool,
+}
+
+impl DashboardState {
+    pub fn new(overlay: &Overlay) -> Self {
+        DashboardState {
+            visible: overlay.is_dashboard_visible(),
+        }
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Updates the state from `Event::DashboardActivated` and `Event::DashboardDeactivated`, returning whether the
+    /// visibility changed.
+    pub fn handle_event(&mut self, event: &Event) -> bool {
+        let visible = match *event {
+            Event::DashboardActivated => true,
+            Event::DashboardDeactivated => false,
+            _ => return false,
+        };
+        let changed = self.visible != visible;
+        self.visible = visible;
+        changed
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dashboard_state_follows_events() {
+        let mut state = DashboardState { visible: false };
+        assert!(!state.handle_event(&Event::DashboardDeactivated));
+        assert!(state.handle_event(&Event::DashboardActivated));
+        assert!(state.is_visible());
+        assert!(!state.handle_event(&Event::IpdChanged));
+        assert!(!state.handle_event(&Event::DashboardActivated));
+        assert!(state.handle_event(&Event::DashboardDeactivated));
+        assert!(!state.is_visible());
+    }
+}
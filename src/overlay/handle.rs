@@ -0,0 +1,140 @@
+use std::{mem, path::Path};
+
+use openvr_sys::VROverlayHandle_t;
+
+use super::{OverlayFlag, VROverlayError};
+use crate::compositor::texture::{Bounds, Texture};
+use crate::{Overlay, TrackedDeviceIndex, TrackingUniverseOrigin};
+
+/// An overlay owned by this application, destroyed when dropped.
+///
+/// The methods mirror the per-overlay methods of `Overlay`, which can still be called with the raw handle returned by
+/// `OverlayHandle::raw`.
+pub struct OverlayHandle<'a> {
+    pub(super) overlay: &'a Overlay,
+    pub(super) handle: VROverlayHandle_t,
+}
+
+impl<'a> OverlayHandle<'a> {
+    /// Takes ownership of an existing overlay.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid overlay that is not destroyed by anything else.
+    pub unsafe fn from_raw(overlay: &'a Overlay, handle: VROverlayHandle_t) -> Self {
+        OverlayHandle { overlay, handle }
+    }
+
+    pub fn raw(&self) -> VROverlayHandle_t {
+        self.handle
+    }
+
+    /// Gives up ownership of the overlay without destroying it.
+    ///
+    /// The overlay lives until it is destroyed with `Overlay::destroy`, or until the application shuts down.
+    pub fn leak(self) -> VROverlayHandle_t {
+        let handle = self.handle;
+        mem::forget(self);
+        handle
+    }
+
+    pub fn show(&self) -> Result<(), VROverlayError> {
+        self.overlay.show(self.handle)
+    }
+
+    pub fn hide(&self) -> Result<(), VROverlayError> {
+        self.overlay.hide(self.handle)
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.overlay.is_visible(self.handle)
+    }
+
+    pub fn set_flag(&self, flag: OverlayFlag, enabled: bool) -> Result<(), VROverlayError> {
+        self.overlay.set_flag(self.handle, flag, enabled)
+    }
+
+    pub fn flag(&self, flag: OverlayFlag) -> Result<bool, VROverlayError> {
+        self.overlay.flag(self.handle, flag)
+    }
+
+    pub fn set_color(&self, red: f32, green: f32, blue: f32) -> Result<(), VROverlayError> {
+        self.overlay.set_color(self.handle, red, green, blue)
+    }
+
+    pub fn set_alpha(&self, alpha: f32) -> Result<(), VROverlayError> {
+        self.overlay.set_alpha(self.handle, alpha)
+    }
+
+    pub fn set_sort_order(&self, sort_order: u32) -> Result<(), VROverlayError> {
+        self.overlay.set_sort_order(self.handle, sort_order)
+    }
+
+    pub fn set_width_in_meters(&self, width_in_meters: f32) -> Result<(), VROverlayError> {
+        self.overlay
+            .set_width_in_meters(self.handle, width_in_meters)
+    }
+
+    pub fn set_texture_bounds(&self, bounds: &Bounds) -> Result<(), VROverlayError> {
+        self.overlay.set_texture_bounds(self.handle, bounds)
+    }
+
+    pub fn set_transform_absolute(
+        &self,
+        origin: TrackingUniverseOrigin,
+        origin_to_overlay: &[[f32; 4]; 3],
+    ) -> Result<(), VROverlayError> {
+        self.overlay
+            .set_transform_absolute(self.handle, origin, origin_to_overlay)
+    }
+
+    pub fn set_transform_tracked_device_relative(
+        &self,
+        device: TrackedDeviceIndex,
+        device_to_overlay: &[[f32; 4]; 3],
+    ) -> Result<(), VROverlayError> {
+        self.overlay
+            .set_transform_tracked_device_relative(self.handle, device, device_to_overlay)
+    }
+
+    pub fn set_transform_tracked_device_component(
+        &self,
+        device: TrackedDeviceIndex,
+        component_name: &str,
+    ) -> Result<(), VROverlayError> {
+        self.overlay
+            .set_transform_tracked_device_component(self.handle, device, component_name)
+    }
+
+    /// # Safety
+    ///
+    /// See `Overlay::set_texture`.
+    pub unsafe fn set_texture(&self, texture: &Texture) -> Result<(), VROverlayError> {
+        self.overlay.set_texture(self.handle, texture)
+    }
+
+    pub fn clear_texture(&self) -> Result<(), VROverlayError> {
+        self.overlay.clear_texture(self.handle)
+    }
+
+    pub fn set_raw(
+        &self,
+        buffer: &[u8],
+        width: u32,
+        height: u32,
+        bytes_per_pixel: u32,
+    ) -> Result<(), VROverlayError> {
+        self.overlay
+            .set_raw(self.handle, buffer, width, height, bytes_per_pixel)
+    }
+
+    pub fn set_from_file(&self, file_path: &Path) -> Result<(), VROverlayError> {
+        self.overlay.set_from_file(self.handle, file_path)
+    }
+}
+
+impl<'a> Drop for OverlayHandle<'a> {
+    fn drop(&mut self) {
+        let _ = self.overlay.destroy(self.handle);
+    }
+}
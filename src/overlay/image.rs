@@ -0,0 +1,183 @@
+//! Uploading CPU-side images to overlays and reading them back.
+//!
+//! GPU textures are set with `Overlay::set_texture` instead.
+
+use std::ptr;
+
+use openvr_sys as sys;
+use openvr_sys::{EVROverlayError_VROverlayError_None, VROverlayHandle_t};
+
+use super::{OverlayHandle, VROverlayError};
+use crate::Overlay;
+
+/// A contiguous RGBA8 image, with rows tightly packed from the top.
+///
+/// Implement this for your own image types to upload them with `Overlay::set_image`.
+pub trait RgbaBuffer {
+    /// Width and height in pixels
+    fn dimensions(&self) -> (u32, u32);
+    /// Exactly `width * height * 4` bytes
+    fn rgba_bytes(&self) -> &[u8];
+}
+
+/// An owned RGBA8 image, as read back by `Overlay::get_overlay_image_data`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RgbaImage {
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+impl RgbaBuffer for RgbaImage {
+    fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    fn rgba_bytes(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl<'a, T: RgbaBuffer + ?Sized> RgbaBuffer for &'a T {
+    fn dimensions(&self) -> (u32, u32) {
+        (**self).dimensions()
+    }
+
+    fn rgba_bytes(&self) -> &[u8] {
+        (**self).rgba_bytes()
+    }
+}
+
+impl Overlay {
+    /** Uploads an RGBA8 image as the overlay's texture. This function can only be called by the overlay's renderer
+     * process.
+     *
+     * Fails with `VROverlayError_InvalidParameter` unless `rgba` is exactly `width * height * 4` bytes. */
+    pub fn set_raw_rgba(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        width: u32,
+        height: u32,
+        rgba: &[u8],
+    ) -> Result<(), VROverlayError> {
+        if rgba.len() as u64 != width as u64 * height as u64 * 4 {
+            return Err(sys::EVROverlayError_VROverlayError_InvalidParameter.into());
+        }
+        self.set_raw(overlay_handle, rgba, width, height, 4)
+    }
+
+    /** Uploads any RGBA8 image as the overlay's texture. See `set_raw_rgba`. */
+    pub fn set_image(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        image: impl RgbaBuffer,
+    ) -> Result<(), VROverlayError> {
+        let (width, height) = image.dimensions();
+        self.set_raw_rgba(overlay_handle, width, height, image.rgba_bytes())
+    }
+
+    /** Get the size of the overlay texture */
+    pub fn get_overlay_texture_size(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+    ) -> Result<(u32, u32), VROverlayError> {
+        let mut width = 0;
+        let mut height = 0;
+        let result = unsafe {
+            self.0.GetOverlayTextureSize.unwrap()(overlay_handle, &mut width, &mut height)
+        };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok((width, height))
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Gets the raw image data from an overlay. Overlay image data is always returned as RGBA data, 4 bytes per
+     * pixel.
+     *
+     * Retries with a larger buffer if the image grows while it is being read. */
+    pub fn get_overlay_image_data(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+    ) -> Result<RgbaImage, VROverlayError> {
+        let mut width = 0;
+        let mut height = 0;
+        let mut data = Vec::new();
+        loop {
+            let result = unsafe {
+                self.0.GetOverlayImageData.unwrap()(
+                    overlay_handle,
+                    if data.is_empty() {
+                        ptr::null_mut()
+                    } else {
+                        data.as_mut_ptr() as *mut _
+                    },
+                    data.len() as u32,
+                    &mut width,
+                    &mut height,
+                )
+            };
+            let len = width as usize * height as usize * 4;
+            if result == EVROverlayError_VROverlayError_None && len <= data.len() {
+                // The image may have shrunk since the buffer was sized
+                data.truncate(len);
+                return Ok(RgbaImage {
+                    width,
+                    height,
+                    data,
+                });
+            } else if result == EVROverlayError_VROverlayError_None
+                || result == sys::EVROverlayError_VROverlayError_ArrayTooSmall
+            {
+                data.resize(len, 0);
+            } else {
+                return Err(result.into());
+            }
+        }
+    }
+}
+
+impl<'a> OverlayHandle<'a> {
+    pub fn set_raw_rgba(&self, width: u32, height: u32, rgba: &[u8]) -> Result<(), VROverlayError> {
+        self.overlay.set_raw_rgba(self.handle, width, height, rgba)
+    }
+
+    pub fn set_image(&self, image: impl RgbaBuffer) -> Result<(), VROverlayError> {
+        self.overlay.set_image(self.handle, image)
+    }
+
+    pub fn get_overlay_texture_size(&self) -> Result<(u32, u32), VROverlayError> {
+        self.overlay.get_overlay_texture_size(self.handle)
+    }
+
+    pub fn get_overlay_image_data(&self) -> Result<RgbaImage, VROverlayError> {
+        self.overlay.get_overlay_image_data(self.handle)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // The length check returns before any function is called
+    static TABLE: sys::VR_IVROverlay_FnTable = unsafe { std::mem::zeroed() };
+
+    #[test]
+    fn set_raw_rgba_checks_length() {
+        let overlay = Overlay(&TABLE);
+        let check = |width, height, len| {
+            let rgba = vec![0; len];
+            match overlay.set_raw_rgba(1, width, height, &rgba) {
+                Err(VROverlayError(e)) => e,
+                Ok(()) => unreachable!(),
+            }
+        };
+        let invalid = sys::EVROverlayError_VROverlayError_InvalidParameter;
+        assert_eq!(check(2, 3, 23), invalid);
+        assert_eq!(check(2, 3, 25), invalid);
+        assert_eq!(check(0, 3, 4), invalid);
+        // 65536 * 16384 * 4 wraps to 0 in u32
+        assert_eq!(check(65536, 16384, 0), invalid);
+    }
+}
@@ -0,0 +1,274 @@
+//! Mouse-style interaction with overlays.
+
+use std::mem;
+
+use openvr_sys as sys;
+use openvr_sys::{EVROverlayError_VROverlayError_None, VROverlayHandle_t};
+
+use super::{OverlayHandle, VROverlayError};
+use crate::system::EventInfo;
+use crate::{Overlay, TrackingUniverseOrigin};
+
+/** Types of input supported by VR Overlays */
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum OverlayInputMethod {
+    /** No input events will be generated automatically for this overlay */
+    None = sys::VROverlayInputMethod_VROverlayInputMethod_None as isize,
+    /** Tracked controllers will get mouse events automatically */
+    Mouse = sys::VROverlayInputMethod_VROverlayInputMethod_Mouse as isize,
+}
+
+/// Where a ray hit an overlay, as computed by `Overlay::compute_intersection`
+#[derive(Debug, Copy, Clone)]
+pub struct OverlayIntersection {
+    pub point: [f32; 3],
+    pub normal: [f32; 3],
+    /// Texture coordinates of the hit, with the origin at the bottom left like `event::Mouse::position`
+    pub uv: (f32, f32),
+    /// Distance along the ray from the source
+    pub distance: f32,
+}
+
+/// A region of an overlay that accepts intersections, in the units of `Overlay::set_mouse_scale`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum IntersectionMaskPrimitive {
+    Rectangle {
+        top_left: (f32, f32),
+        width: f32,
+        height: f32,
+    },
+    Circle {
+        center: (f32, f32),
+        radius: f32,
+    },
+}
+
+impl From<IntersectionMaskPrimitive> for sys::VROverlayIntersectionMaskPrimitive_t {
+    fn from(x: IntersectionMaskPrimitive) -> Self {
+        match x {
+            IntersectionMaskPrimitive::Rectangle {
+                top_left,
+                width,
+                height,
+            } => sys::VROverlayIntersectionMaskPrimitive_t {
+                m_nPrimitiveType:
+                    sys::EVROverlayIntersectionMaskPrimitiveType_OverlayIntersectionPrimitiveType_Rectangle,
+                m_Primitive: sys::VROverlayIntersectionMaskPrimitive_Data_t {
+                    m_Rectangle: sys::IntersectionMaskRectangle_t {
+                        m_flTopLeftX: top_left.0,
+                        m_flTopLeftY: top_left.1,
+                        m_flWidth: width,
+                        m_flHeight: height,
+                    },
+                },
+            },
+            IntersectionMaskPrimitive::Circle { center, radius } => {
+                sys::VROverlayIntersectionMaskPrimitive_t {
+                    m_nPrimitiveType:
+                        sys::EVROverlayIntersectionMaskPrimitiveType_OverlayIntersectionPrimitiveType_Circle,
+                    m_Primitive: sys::VROverlayIntersectionMaskPrimitive_Data_t {
+                        m_Circle: sys::IntersectionMaskCircle_t {
+                            m_flCenterX: center.0,
+                            m_flCenterY: center.1,
+                            m_flRadius: radius,
+                        },
+                    },
+                }
+            }
+        }
+    }
+}
+
+impl Overlay {
+    /** Returns the current input settings for the specified overlay. */
+    pub fn input_method(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+    ) -> Result<OverlayInputMethod, VROverlayError> {
+        let mut method = sys::VROverlayInputMethod_VROverlayInputMethod_None;
+        let result = unsafe { self.0.GetOverlayInputMethod.unwrap()(overlay_handle, &mut method) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(match method {
+                sys::VROverlayInputMethod_VROverlayInputMethod_Mouse => OverlayInputMethod::Mouse,
+                _ => OverlayInputMethod::None,
+            })
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Sets the input settings for the specified overlay. */
+    pub fn set_input_method(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        method: OverlayInputMethod,
+    ) -> Result<(), VROverlayError> {
+        let result = unsafe {
+            self.0.SetOverlayInputMethod.unwrap()(
+                overlay_handle,
+                method as sys::VROverlayInputMethod,
+            )
+        };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Gets the mouse scaling factor that is used for mouse events. The actual texture may be a different size, but
+     * this is typically the size of the underlying UI in pixels. */
+    pub fn mouse_scale(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+    ) -> Result<(f32, f32), VROverlayError> {
+        let mut scale = sys::HmdVector2_t { v: [0.0; 2] };
+        let result = unsafe { self.0.GetOverlayMouseScale.unwrap()(overlay_handle, &mut scale) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok((scale.v[0], scale.v[1]))
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Sets the mouse scaling factor that is used for mouse events. The actual texture may be a different size, but
+     * this is typically the size of the underlying UI in pixels (not in world space). */
+    pub fn set_mouse_scale(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        scale: (f32, f32),
+    ) -> Result<(), VROverlayError> {
+        let mut scale = sys::HmdVector2_t {
+            v: [scale.0, scale.1],
+        };
+        let result = unsafe { self.0.SetOverlayMouseScale.unwrap()(overlay_handle, &mut scale) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Computes where the ray intersects the overlay. Returns None if there is no intersection. */
+    pub fn compute_intersection(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        origin: TrackingUniverseOrigin,
+        source: [f32; 3],
+        direction: [f32; 3],
+    ) -> Option<OverlayIntersection> {
+        let mut params = sys::VROverlayIntersectionParams_t {
+            vSource: sys::HmdVector3_t { v: source },
+            vDirection: sys::HmdVector3_t { v: direction },
+            eOrigin: origin as sys::ETrackingUniverseOrigin,
+        };
+        let mut results = mem::MaybeUninit::<sys::VROverlayIntersectionResults_t>::uninit();
+        let hit = unsafe {
+            self.0.ComputeOverlayIntersection.unwrap()(
+                overlay_handle,
+                &mut params,
+                results.as_mut_ptr(),
+            )
+        };
+        if hit {
+            let results = unsafe { results.assume_init() };
+            Some(OverlayIntersection {
+                point: results.vPoint.v,
+                normal: results.vNormal.v,
+                uv: (results.vUVs.v[0], results.vUVs.v[1]),
+                distance: results.fDistance,
+            })
+        } else {
+            None
+        }
+    }
+
+    /** Sets a list of primitives to be used for controller ray intersection typically the size of the underlying UI
+     * in pixels (not in world space). */
+    pub fn set_intersection_mask(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        primitives: &[IntersectionMaskPrimitive],
+    ) -> Result<(), VROverlayError> {
+        let mut primitives: Vec<sys::VROverlayIntersectionMaskPrimitive_t> =
+            primitives.iter().map(|&x| x.into()).collect();
+        let result = unsafe {
+            self.0.SetOverlayIntersectionMask.unwrap()(
+                overlay_handle,
+                primitives.as_mut_ptr(),
+                primitives.len() as u32,
+                mem::size_of::<sys::VROverlayIntersectionMaskPrimitive_t>() as u32,
+            )
+        };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Returns true if the specified overlay is the hover target. An overlay is the hover target when it is the last
+     * overlay "moused over" by the virtual mouse pointer */
+    pub fn is_hover_target(&self, overlay_handle: VROverlayHandle_t) -> bool {
+        unsafe { self.0.IsHoverTargetOverlay.unwrap()(overlay_handle) }
+    }
+
+    /** Returns the next event on the overlay's event queue, if there is one. */
+    pub fn poll_next_event(&self, overlay_handle: VROverlayHandle_t) -> Option<EventInfo> {
+        let mut event = mem::MaybeUninit::uninit();
+        if unsafe {
+            self.0.PollNextOverlayEvent.unwrap()(
+                overlay_handle,
+                event.as_mut_ptr(),
+                mem::size_of_val(&event) as u32,
+            )
+        } {
+            unsafe { Some(event.assume_init().into()) }
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> OverlayHandle<'a> {
+    pub fn input_method(&self) -> Result<OverlayInputMethod, VROverlayError> {
+        self.overlay.input_method(self.handle)
+    }
+
+    pub fn set_input_method(&self, method: OverlayInputMethod) -> Result<(), VROverlayError> {
+        self.overlay.set_input_method(self.handle, method)
+    }
+
+    pub fn mouse_scale(&self) -> Result<(f32, f32), VROverlayError> {
+        self.overlay.mouse_scale(self.handle)
+    }
+
+    pub fn set_mouse_scale(&self, scale: (f32, f32)) -> Result<(), VROverlayError> {
+        self.overlay.set_mouse_scale(self.handle, scale)
+    }
+
+    pub fn compute_intersection(
+        &self,
+        origin: TrackingUniverseOrigin,
+        source: [f32; 3],
+        direction: [f32; 3],
+    ) -> Option<OverlayIntersection> {
+        self.overlay
+            .compute_intersection(self.handle, origin, source, direction)
+    }
+
+    pub fn set_intersection_mask(
+        &self,
+        primitives: &[IntersectionMaskPrimitive],
+    ) -> Result<(), VROverlayError> {
+        self.overlay.set_intersection_mask(self.handle, primitives)
+    }
+
+    pub fn is_hover_target(&self) -> bool {
+        self.overlay.is_hover_target(self.handle)
+    }
+
+    pub fn poll_next_event(&self) -> Option<EventInfo> {
+        self.overlay.poll_next_event(self.handle)
+    }
+}
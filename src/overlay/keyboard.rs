@@ -0,0 +1,196 @@
+//! The SteamVR virtual keyboard.
+//!
+//! Typed characters are delivered as `Event::KeyboardCharInput`, followed by `Event::KeyboardDone` or
+//! `Event::KeyboardClosed`, on the event queue of the overlay the keyboard was shown for, or on the system event queue.
+
+use std::ffi::{CStr, CString};
+
+use openvr_sys as sys;
+use openvr_sys::{EVROverlayError_VROverlayError_None, VROverlayHandle_t};
+
+use super::{OverlayHandle, VROverlayError};
+use crate::{Overlay, TrackingUniverseOrigin};
+
+/** Input modes for the Big Picture gamepad text entry */
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GamepadTextInputMode {
+    Normal = sys::EGamepadTextInputMode_k_EGamepadTextInputModeNormal as isize,
+    Password = sys::EGamepadTextInputMode_k_EGamepadTextInputModePassword as isize,
+    Submit = sys::EGamepadTextInputMode_k_EGamepadTextInputModeSubmit as isize,
+}
+
+/** Controls number of allowed lines for the Big Picture gamepad text entry */
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GamepadTextInputLineMode {
+    SingleLine = sys::EGamepadTextInputLineMode_k_EGamepadTextInputLineModeSingleLine as isize,
+    MultipleLines =
+        sys::EGamepadTextInputLineMode_k_EGamepadTextInputLineModeMultipleLines as isize,
+}
+
+pub type KeyboardFlags = u32;
+
+pub mod keyboard_flags {
+    use super::*;
+    /** Makes the keyboard send key events immediately instead of accumulating a buffer */
+    pub const MINIMAL: KeyboardFlags = sys::EKeyboardFlags_KeyboardFlag_Minimal as KeyboardFlags;
+    /** Makes the keyboard take all focus and dismiss when clicking off the panel */
+    pub const MODAL: KeyboardFlags = sys::EKeyboardFlags_KeyboardFlag_Modal as KeyboardFlags;
+    /** Shows arrow keys on the keyboard when in minimal mode. Buffered (non-minimal) mode always has them. In minimal
+     * mode, when arrow keys are pressed, they send ANSI escape sequences (e.g. "\x1b[D" for left arrow). */
+    pub const SHOW_ARROW_KEYS: KeyboardFlags =
+        sys::EKeyboardFlags_KeyboardFlag_ShowArrowKeys as KeyboardFlags;
+    /** Hides the DONE button */
+    pub const HIDE_DONE_KEY: KeyboardFlags =
+        sys::EKeyboardFlags_KeyboardFlag_HideDoneKey as KeyboardFlags;
+}
+
+impl Overlay {
+    /** Show the virtual keyboard to accept input. In most cases, you should pass KeyboardFlag_Modal to enable modal
+     * overlay behavior on the keyboard itself. */
+    #[allow(clippy::too_many_arguments)]
+    pub fn show_keyboard(
+        &self,
+        input_mode: GamepadTextInputMode,
+        line_mode: GamepadTextInputLineMode,
+        flags: KeyboardFlags,
+        description: &str,
+        char_max: u32,
+        existing_text: &str,
+        user_value: u64,
+    ) -> Result<(), VROverlayError> {
+        let description = CString::new(description).unwrap();
+        let existing_text = CString::new(existing_text).unwrap();
+        let result = unsafe {
+            self.0.ShowKeyboard.unwrap()(
+                input_mode as sys::EGamepadTextInputMode,
+                line_mode as sys::EGamepadTextInputLineMode,
+                flags,
+                description.as_ptr() as _,
+                char_max,
+                existing_text.as_ptr() as _,
+                user_value,
+            )
+        };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Show the virtual keyboard for the given overlay. Keyboard events are sent to that overlay's event queue. */
+    #[allow(clippy::too_many_arguments)]
+    pub fn show_keyboard_for_overlay(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        input_mode: GamepadTextInputMode,
+        line_mode: GamepadTextInputLineMode,
+        flags: KeyboardFlags,
+        description: &str,
+        char_max: u32,
+        existing_text: &str,
+        user_value: u64,
+    ) -> Result<(), VROverlayError> {
+        let description = CString::new(description).unwrap();
+        let existing_text = CString::new(existing_text).unwrap();
+        let result = unsafe {
+            self.0.ShowKeyboardForOverlay.unwrap()(
+                overlay_handle,
+                input_mode as sys::EGamepadTextInputMode,
+                line_mode as sys::EGamepadTextInputLineMode,
+                flags,
+                description.as_ptr() as _,
+                char_max,
+                existing_text.as_ptr() as _,
+                user_value,
+            )
+        };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Get the text that was entered into the text input */
+    pub fn get_keyboard_text(&self) -> String {
+        let mut buffer = vec![0; 4096];
+        unsafe {
+            self.0.GetKeyboardText.unwrap()(buffer.as_mut_ptr(), buffer.len() as u32);
+            CStr::from_ptr(buffer.as_ptr())
+                .to_string_lossy()
+                .into_owned()
+        }
+    }
+
+    /** Hide the virtual keyboard */
+    pub fn hide_keyboard(&self) {
+        unsafe { self.0.HideKeyboard.unwrap()() }
+    }
+
+    /** Set the position of the keyboard in world space */
+    pub fn set_keyboard_transform_absolute(
+        &self,
+        origin: TrackingUniverseOrigin,
+        origin_to_keyboard: &[[f32; 4]; 3],
+    ) {
+        let mut transform = sys::HmdMatrix34_t {
+            m: *origin_to_keyboard,
+        };
+        unsafe {
+            self.0.SetKeyboardTransformAbsolute.unwrap()(
+                origin as sys::ETrackingUniverseOrigin,
+                &mut transform,
+            )
+        }
+    }
+
+    /** Set the position of the keyboard in overlay space by telling it to avoid a rectangle in the overlay. Rectangle
+     * coords have (0,0) in the bottom left */
+    pub fn set_keyboard_position_for_overlay(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        top_left: (f32, f32),
+        bottom_right: (f32, f32),
+    ) {
+        let avoid_rect = sys::HmdRect2_t {
+            vTopLeft: sys::HmdVector2_t {
+                v: [top_left.0, top_left.1],
+            },
+            vBottomRight: sys::HmdVector2_t {
+                v: [bottom_right.0, bottom_right.1],
+            },
+        };
+        unsafe { self.0.SetKeyboardPositionForOverlay.unwrap()(overlay_handle, avoid_rect) }
+    }
+}
+
+impl<'a> OverlayHandle<'a> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn show_keyboard(
+        &self,
+        input_mode: GamepadTextInputMode,
+        line_mode: GamepadTextInputLineMode,
+        flags: KeyboardFlags,
+        description: &str,
+        char_max: u32,
+        existing_text: &str,
+        user_value: u64,
+    ) -> Result<(), VROverlayError> {
+        self.overlay.show_keyboard_for_overlay(
+            self.handle,
+            input_mode,
+            line_mode,
+            flags,
+            description,
+            char_max,
+            existing_text,
+            user_value,
+        )
+    }
+
+    pub fn set_keyboard_position(&self, top_left: (f32, f32), bottom_right: (f32, f32)) {
+        self.overlay
+            .set_keyboard_position_for_overlay(self.handle, top_left, bottom_right)
+    }
+}
@@ -0,0 +1,452 @@
+use std::{error::Error, ffi::CString, fmt::Display, path::Path};
+
+use openvr_sys as sys;
+use openvr_sys::{EVROverlayError, EVROverlayError_VROverlayError_None, VROverlayHandle_t};
+
+use crate::compositor::texture::{self, Bounds, Texture};
+use crate::{Overlay, TrackedDeviceIndex, TrackingUniverseOrigin};
+
+pub mod dashboard;
+pub mod handle;
+pub mod image;
+pub mod input;
+pub mod keyboard;
+pub mod properties;
+
+pub use self::dashboard::DashboardState;
+pub use self::handle::OverlayHandle;
+pub use self::image::{RgbaBuffer, RgbaImage};
+pub use self::input::{IntersectionMaskPrimitive, OverlayInputMethod, OverlayIntersection};
+pub use self::keyboard::{
+    keyboard_flags, GamepadTextInputLineMode, GamepadTextInputMode, KeyboardFlags,
+};
+pub use self::properties::{OverlayProperties, StereoLayout};
+
+#[derive(Debug)]
+pub struct VROverlayError(pub EVROverlayError);
+impl Display for VROverlayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EVROverlayError {}", self.0)
+    }
+}
+impl Error for VROverlayError {}
+impl From<EVROverlayError> for VROverlayError {
+    fn from(err: EVROverlayError) -> Self {
+        VROverlayError(err)
+    }
+}
+
+impl Overlay {
+    /** Finds an existing overlay with the specified key. */
+    pub fn find(&self, overlay_key: &str) -> Result<VROverlayHandle_t, VROverlayError> {
+        let mut overlay_handle: VROverlayHandle_t = unsafe { std::mem::zeroed() };
+        let overlay_key = CString::new(overlay_key).unwrap();
+
+        let result =
+            unsafe { self.0.FindOverlay.unwrap()(overlay_key.as_ptr() as _, &mut overlay_handle) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(overlay_handle)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Creates a new named overlay. All overlays start hidden and with default settings.
+     *
+     * The overlay is destroyed when the returned handle is dropped, unless it is leaked with `OverlayHandle::leak`. */
+    pub fn create(
+        &self,
+        overlay_key: &str,
+        overlay_name: &str,
+    ) -> Result<OverlayHandle<'_>, VROverlayError> {
+        let mut overlay_handle: VROverlayHandle_t = unsafe { std::mem::zeroed() };
+        let overlay_key = CString::new(overlay_key).unwrap();
+        let overlay_name = CString::new(overlay_name).unwrap();
+
+        let result = unsafe {
+            self.0.CreateOverlay.unwrap()(
+                overlay_key.as_ptr() as _,
+                overlay_name.as_ptr() as _,
+                &mut overlay_handle,
+            )
+        };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(unsafe { OverlayHandle::from_raw(self, overlay_handle) })
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Destroys the specified overlay. When an application calls VR_Shutdown all overlays created by that app are
+     * automatically destroyed. */
+    pub fn destroy(&self, overlay_handle: VROverlayHandle_t) -> Result<(), VROverlayError> {
+        let result = unsafe { self.0.DestroyOverlay.unwrap()(overlay_handle) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Shows the VR overlay. For dashboard overlays, only the Dashboard Manager is allowed to call this. */
+    pub fn show(&self, overlay_handle: VROverlayHandle_t) -> Result<(), VROverlayError> {
+        let result = unsafe { self.0.ShowOverlay.unwrap()(overlay_handle) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Hides the VR overlay. For dashboard overlays, only the Dashboard Manager is allowed to call this. */
+    pub fn hide(&self, overlay_handle: VROverlayHandle_t) -> Result<(), VROverlayError> {
+        let result = unsafe { self.0.HideOverlay.unwrap()(overlay_handle) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Returns true if the overlay is visible. */
+    pub fn is_visible(&self, overlay_handle: VROverlayHandle_t) -> bool {
+        unsafe { self.0.IsOverlayVisible.unwrap()(overlay_handle) }
+    }
+
+    /** Specify flag setting for a given overlay */
+    pub fn set_flag(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        flag: OverlayFlag,
+        enabled: bool,
+    ) -> Result<(), VROverlayError> {
+        let result = unsafe {
+            self.0.SetOverlayFlag.unwrap()(overlay_handle, flag as sys::VROverlayFlags, enabled)
+        };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Gets flag setting for a given overlay */
+    pub fn flag(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        flag: OverlayFlag,
+    ) -> Result<bool, VROverlayError> {
+        let mut enabled = false;
+        let result = unsafe {
+            self.0.GetOverlayFlag.unwrap()(
+                overlay_handle,
+                flag as sys::VROverlayFlags,
+                &mut enabled,
+            )
+        };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(enabled)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Sets the color tint of the overlay quad. Use 0.0 to 1.0 per channel. */
+    pub fn set_color(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        red: f32,
+        green: f32,
+        blue: f32,
+    ) -> Result<(), VROverlayError> {
+        let result = unsafe { self.0.SetOverlayColor.unwrap()(overlay_handle, red, green, blue) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Sets the alpha of the overlay quad. Use 1.0 for 100 percent opacity to 0.0 for 0 percent opacity. */
+    pub fn set_alpha(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        alpha: f32,
+    ) -> Result<(), VROverlayError> {
+        let result = unsafe { self.0.SetOverlayAlpha.unwrap()(overlay_handle, alpha) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Sets the rendering sort order for the overlay. Overlays are rendered this order:
+     *      Overlays owned by the scene application
+     *      Overlays owned by some other application
+     *
+     * Within a category overlays are rendered lowest sort order to highest sort order. Overlays with the same
+     * sort order are rendered back to front base on distance from the HMD.
+     *
+     * Sort order defaults to 0. */
+    pub fn set_sort_order(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        sort_order: u32,
+    ) -> Result<(), VROverlayError> {
+        let result = unsafe { self.0.SetOverlaySortOrder.unwrap()(overlay_handle, sort_order) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Sets the width of the overlay quad in meters. By default overlays are rendered on a quad that is 1 meter across */
+    pub fn set_width_in_meters(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        width_in_meters: f32,
+    ) -> Result<(), VROverlayError> {
+        let result =
+            unsafe { self.0.SetOverlayWidthInMeters.unwrap()(overlay_handle, width_in_meters) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Sets the part of the texture to use for the overlay. UV Min is the upper left corner and UV Max is the lower
+     * right corner. By default overlays use the full texture. */
+    pub fn set_texture_bounds(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        bounds: &Bounds,
+    ) -> Result<(), VROverlayError> {
+        let result = unsafe {
+            self.0.SetOverlayTextureBounds.unwrap()(
+                overlay_handle,
+                bounds as *const Bounds as *mut Bounds as *mut _,
+            )
+        };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Sets the transform to absolute tracking origin. */
+    pub fn set_transform_absolute(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        origin: TrackingUniverseOrigin,
+        origin_to_overlay: &[[f32; 4]; 3],
+    ) -> Result<(), VROverlayError> {
+        let mut transform = sys::HmdMatrix34_t {
+            m: *origin_to_overlay,
+        };
+        let result = unsafe {
+            self.0.SetOverlayTransformAbsolute.unwrap()(
+                overlay_handle,
+                origin as sys::ETrackingUniverseOrigin,
+                &mut transform,
+            )
+        };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Sets the transform to relative to the transform of the specified tracked device. */
+    pub fn set_transform_tracked_device_relative(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        device: TrackedDeviceIndex,
+        device_to_overlay: &[[f32; 4]; 3],
+    ) -> Result<(), VROverlayError> {
+        let mut transform = sys::HmdMatrix34_t {
+            m: *device_to_overlay,
+        };
+        let result = unsafe {
+            self.0.SetOverlayTransformTrackedDeviceRelative.unwrap()(
+                overlay_handle,
+                device,
+                &mut transform,
+            )
+        };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Sets the transform to draw the overlay on a rendermodel component mesh instead of a quad. This will only draw
+     * when the system is drawing the device. Overlays with this transform type cannot receive mouse events. */
+    pub fn set_transform_tracked_device_component(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        device: TrackedDeviceIndex,
+        component_name: &str,
+    ) -> Result<(), VROverlayError> {
+        let component_name = CString::new(component_name).unwrap();
+        let result = unsafe {
+            self.0.SetOverlayTransformTrackedDeviceComponent.unwrap()(
+                overlay_handle,
+                device,
+                component_name.as_ptr() as _,
+            )
+        };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Texture to draw for the overlay. This function can only be called by the overlay's creator or renderer process
+     * (see SetOverlayRenderingPid).
+     *
+     * # Safety
+     *
+     * The handle to the texture must be valid for the graphics API in use, and must remain valid until the overlay is
+     * given a new texture or cleared. */
+    pub unsafe fn set_texture(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        texture: &Texture,
+    ) -> Result<(), VROverlayError> {
+        use self::texture::Handle::*;
+        let mut texture = sys::Texture_t {
+            handle: match texture.handle {
+                Vulkan(ref x) => x as *const _ as *mut _,
+                OpenGLTexture(x) => x as *mut _,
+                OpenGLRenderBuffer(x) => x as *mut _,
+            },
+            eType: match texture.handle {
+                Vulkan(_) => sys::ETextureType_TextureType_Vulkan,
+                OpenGLTexture(_) => sys::ETextureType_TextureType_OpenGL,
+                OpenGLRenderBuffer(_) => sys::ETextureType_TextureType_OpenGL,
+            },
+            eColorSpace: texture.color_space as sys::EColorSpace,
+        };
+        let result = self.0.SetOverlayTexture.unwrap()(overlay_handle, &mut texture);
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Use this to tell the overlay system to release the texture set for this overlay. */
+    pub fn clear_texture(&self, overlay_handle: VROverlayHandle_t) -> Result<(), VROverlayError> {
+        let result = unsafe { self.0.ClearOverlayTexture.unwrap()(overlay_handle) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Separate interface for providing the data as a stream of bytes, but there is an upper bound on data that can
+     * be sent. This function can only be called by the overlay's renderer process.
+     *
+     * Fails with `VROverlayError_InvalidParameter` if `buffer` is shorter than `width * height * bytes_per_pixel`. */
+    pub fn set_raw(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        buffer: &[u8],
+        width: u32,
+        height: u32,
+        bytes_per_pixel: u32,
+    ) -> Result<(), VROverlayError> {
+        if (buffer.len() as u64) < width as u64 * height as u64 * bytes_per_pixel as u64 {
+            return Err(sys::EVROverlayError_VROverlayError_InvalidParameter.into());
+        }
+        let result = unsafe {
+            self.0.SetOverlayRaw.unwrap()(
+                overlay_handle,
+                buffer.as_ptr() as *mut _,
+                width,
+                height,
+                bytes_per_pixel,
+            )
+        };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Separate interface for providing the image through a filename: can be png or jpg, and should not be bigger
+     * than 1920x1080. This function can only be called by the overlay's renderer process */
+    pub fn set_from_file(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        file_path: &Path,
+    ) -> Result<(), VROverlayError> {
+        let file_path = CString::new(file_path.to_string_lossy().into_owned()).unwrap();
+        let result =
+            unsafe { self.0.SetOverlayFromFile.unwrap()(overlay_handle, file_path.as_ptr() as _) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+}
+
+/** Overlay control settings */
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum OverlayFlag {
+    /** Set this flag on a dashboard overlay to prevent a tab from showing up for that overlay */
+    NoDashboardTab = sys::VROverlayFlags_VROverlayFlags_NoDashboardTab as isize,
+    /** When this is set the overlay will receive VREvent_ScrollDiscrete events like a mouse wheel. Requires mouse
+     * input mode. */
+    SendVRDiscreteScrollEvents =
+        sys::VROverlayFlags_VROverlayFlags_SendVRDiscreteScrollEvents as isize,
+    /** Indicates that the overlay would like to receive */
+    SendVRTouchpadEvents = sys::VROverlayFlags_VROverlayFlags_SendVRTouchpadEvents as isize,
+    /** If set this will render a vertical scroll wheel on the primary controller, only needed if not using
+     * VROverlayFlags_SendVRScrollEvents but you still want to represent a scroll wheel */
+    ShowTouchPadScrollWheel = sys::VROverlayFlags_VROverlayFlags_ShowTouchPadScrollWheel as isize,
+    /** If this is set ownership and render access to the overlay are transferred to the new scene process on a call
+     * to IVRApplications::LaunchInternalProcess */
+    TransferOwnershipToInternalProcess =
+        sys::VROverlayFlags_VROverlayFlags_TransferOwnershipToInternalProcess as isize,
+    /** If set, renders 50% of the texture in each eye, side by side. Texture is left/right */
+    SideBySideParallel = sys::VROverlayFlags_VROverlayFlags_SideBySide_Parallel as isize,
+    /** If set, renders 50% of the texture in each eye, side by side. Texture is crossed and right/left */
+    SideBySideCrossed = sys::VROverlayFlags_VROverlayFlags_SideBySide_Crossed as isize,
+    /** Texture is a panorama */
+    Panorama = sys::VROverlayFlags_VROverlayFlags_Panorama as isize,
+    /** Texture is a stereo panorama */
+    StereoPanorama = sys::VROverlayFlags_VROverlayFlags_StereoPanorama as isize,
+    /** If this is set on an overlay owned by the scene application that overlay will be sorted with the "Other"
+     * overlays on top of all other scene overlays */
+    SortWithNonSceneOverlays = sys::VROverlayFlags_VROverlayFlags_SortWithNonSceneOverlays as isize,
+    /** If set, the overlay will be shown in the dashboard, otherwise it will be hidden. */
+    VisibleInDashboard = sys::VROverlayFlags_VROverlayFlags_VisibleInDashboard as isize,
+    /** If this is set and the overlay's input method is not none, the system-wide laser mouse mode will be activated
+     * whenever this overlay is visible. */
+    MakeOverlaysInteractiveIfVisible =
+        sys::VROverlayFlags_VROverlayFlags_MakeOverlaysInteractiveIfVisible as isize,
+    /** If this is set the overlay will receive smooth VREvent_ScrollSmooth that emulate trackpad scrolling. Requires
+     * mouse input mode. */
+    SendVRSmoothScrollEvents = sys::VROverlayFlags_VROverlayFlags_SendVRSmoothScrollEvents as isize,
+    /** If this is set, the overlay texture will be protected content, preventing unauthorized reads. */
+    ProtectedContent = sys::VROverlayFlags_VROverlayFlags_ProtectedContent as isize,
+    /** If this is set, the laser mouse splat will not be drawn over this overlay. The overlay will be responsible for
+     * drawing its own "cursor". */
+    HideLaserIntersection = sys::VROverlayFlags_VROverlayFlags_HideLaserIntersection as isize,
+    /** If this is set, clicking away from the overlay will cause it to receive a VREvent_Modal_Cancel event. This is
+     * ignored for dashboard overlays. */
+    WantsModalBehavior = sys::VROverlayFlags_VROverlayFlags_WantsModalBehavior as isize,
+    /** If this is set, alpha composition assumes the texture is pre-multiplied */
+    IsPremultiplied = sys::VROverlayFlags_VROverlayFlags_IsPremultiplied as isize,
+}
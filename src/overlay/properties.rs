@@ -0,0 +1,457 @@
+//! Appearance and placement settings of an overlay, and `OverlayProperties` to save and restore them together.
+
+use openvr_sys as sys;
+use openvr_sys::{EVROverlayError_VROverlayError_None, VROverlayHandle_t};
+use serde::{Deserialize, Serialize};
+
+use super::{OverlayFlag, OverlayHandle, VROverlayError};
+use crate::{Overlay, TrackedDeviceIndex, TrackingUniverseOrigin};
+
+impl Overlay {
+    /** Gets the color tint of the overlay quad. */
+    pub fn color(&self, overlay_handle: VROverlayHandle_t) -> Result<[f32; 3], VROverlayError> {
+        let mut color = [0.0; 3];
+        let result = unsafe {
+            self.0.GetOverlayColor.unwrap()(
+                overlay_handle,
+                &mut color[0],
+                &mut color[1],
+                &mut color[2],
+            )
+        };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(color)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Gets the alpha of the overlay quad. By default overlays are rendering at 100 percent alpha (1.0). */
+    pub fn alpha(&self, overlay_handle: VROverlayHandle_t) -> Result<f32, VROverlayError> {
+        let mut alpha = 0.0;
+        let result = unsafe { self.0.GetOverlayAlpha.unwrap()(overlay_handle, &mut alpha) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(alpha)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Gets the sort order of the overlay. See SetOverlaySortOrder for how this works. */
+    pub fn sort_order(&self, overlay_handle: VROverlayHandle_t) -> Result<u32, VROverlayError> {
+        let mut sort_order = 0;
+        let result =
+            unsafe { self.0.GetOverlaySortOrder.unwrap()(overlay_handle, &mut sort_order) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(sort_order)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Returns the width of the overlay quad in meters. By default overlays are rendered on a quad that is 1 meter
+     * across */
+    pub fn width_in_meters(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+    ) -> Result<f32, VROverlayError> {
+        let mut width = 0.0;
+        let result = unsafe { self.0.GetOverlayWidthInMeters.unwrap()(overlay_handle, &mut width) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(width)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Use to draw overlay as a curved surface. Curvature is a percentage from (0..1] where 1 is a fully closed
+     * cylinder. For a specific radius, curvature can be computed as: overlay.width / (2 PI r). */
+    pub fn set_curvature(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        curvature: f32,
+    ) -> Result<(), VROverlayError> {
+        let result = unsafe { self.0.SetOverlayCurvature.unwrap()(overlay_handle, curvature) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Returns the curvature of the overlay as a percentage from (0..1] where 1 is a fully closed cylinder. */
+    pub fn curvature(&self, overlay_handle: VROverlayHandle_t) -> Result<f32, VROverlayError> {
+        let mut curvature = 0.0;
+        let result = unsafe { self.0.GetOverlayCurvature.unwrap()(overlay_handle, &mut curvature) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(curvature)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** For high-quality curved overlays only, set the pitch in radians at which the overlay is pre-curved. */
+    pub fn set_pre_curve_pitch(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        radians: f32,
+    ) -> Result<(), VROverlayError> {
+        let result = unsafe { self.0.SetOverlayPreCurvePitch.unwrap()(overlay_handle, radians) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Returns the pitch in radians at which the overlay is pre-curved. */
+    pub fn pre_curve_pitch(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+    ) -> Result<f32, VROverlayError> {
+        let mut radians = 0.0;
+        let result =
+            unsafe { self.0.GetOverlayPreCurvePitch.unwrap()(overlay_handle, &mut radians) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(radians)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Sets the aspect ratio of the texels in the overlay. 1.0 means the texels are square. 2.0 means the texels
+     * are twice as wide as they are tall. Defaults to 1.0. */
+    pub fn set_texel_aspect(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        texel_aspect: f32,
+    ) -> Result<(), VROverlayError> {
+        let result = unsafe { self.0.SetOverlayTexelAspect.unwrap()(overlay_handle, texel_aspect) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Gets the aspect ratio of the texels in the overlay. Defaults to 1.0 */
+    pub fn texel_aspect(&self, overlay_handle: VROverlayHandle_t) -> Result<f32, VROverlayError> {
+        let mut texel_aspect = 0.0;
+        let result =
+            unsafe { self.0.GetOverlayTexelAspect.unwrap()(overlay_handle, &mut texel_aspect) };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(texel_aspect)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Gets the transform of the overlay, or `None` if it is placed in some way other than relative to a tracking
+     * origin or a tracked device. */
+    pub fn transform(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+    ) -> Result<Option<OverlayTransform>, VROverlayError> {
+        let mut transform_type = sys::VROverlayTransformType_VROverlayTransform_Absolute;
+        let result =
+            unsafe { self.0.GetOverlayTransformType.unwrap()(overlay_handle, &mut transform_type) };
+        if result != EVROverlayError_VROverlayError_None {
+            return Err(result.into());
+        }
+
+        let mut matrix = sys::HmdMatrix34_t { m: [[0.0; 4]; 3] };
+        let (result, transform) = match transform_type {
+            sys::VROverlayTransformType_VROverlayTransform_Absolute => {
+                let mut origin = sys::ETrackingUniverseOrigin_TrackingUniverseStanding;
+                let result = unsafe {
+                    self.0.GetOverlayTransformAbsolute.unwrap()(
+                        overlay_handle,
+                        &mut origin,
+                        &mut matrix,
+                    )
+                };
+                let origin = match origin {
+                    sys::ETrackingUniverseOrigin_TrackingUniverseSeated => {
+                        TrackingUniverseOrigin::Seated
+                    }
+                    sys::ETrackingUniverseOrigin_TrackingUniverseRawAndUncalibrated => {
+                        TrackingUniverseOrigin::RawAndUncalibrated
+                    }
+                    _ => TrackingUniverseOrigin::Standing,
+                };
+                (
+                    result,
+                    OverlayTransform::Absolute {
+                        origin,
+                        origin_to_overlay: matrix.m,
+                    },
+                )
+            }
+            sys::VROverlayTransformType_VROverlayTransform_TrackedDeviceRelative => {
+                let mut device = crate::tracked_device_index::INVALID;
+                let result = unsafe {
+                    self.0.GetOverlayTransformTrackedDeviceRelative.unwrap()(
+                        overlay_handle,
+                        &mut device,
+                        &mut matrix,
+                    )
+                };
+                (
+                    result,
+                    OverlayTransform::TrackedDeviceRelative {
+                        device,
+                        device_to_overlay: matrix.m,
+                    },
+                )
+            }
+            _ => return Ok(None),
+        };
+        if result == EVROverlayError_VROverlayError_None {
+            Ok(Some(transform))
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Sets the transform of the overlay with `set_transform_absolute` or `set_transform_tracked_device_relative`. */
+    pub fn set_transform(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        transform: &OverlayTransform,
+    ) -> Result<(), VROverlayError> {
+        match *transform {
+            OverlayTransform::Absolute {
+                origin,
+                ref origin_to_overlay,
+            } => self.set_transform_absolute(overlay_handle, origin, origin_to_overlay),
+            OverlayTransform::TrackedDeviceRelative {
+                device,
+                ref device_to_overlay,
+            } => self.set_transform_tracked_device_relative(
+                overlay_handle,
+                device,
+                device_to_overlay,
+            ),
+        }
+    }
+
+    /// Reads all of the settings in `OverlayProperties` at once.
+    pub fn properties(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+    ) -> Result<OverlayProperties, VROverlayError> {
+        let stereo = if self.flag(overlay_handle, OverlayFlag::SideBySideParallel)? {
+            StereoLayout::SideBySideParallel
+        } else if self.flag(overlay_handle, OverlayFlag::SideBySideCrossed)? {
+            StereoLayout::SideBySideCrossed
+        } else {
+            StereoLayout::Mono
+        };
+        Ok(OverlayProperties {
+            width_in_meters: self.width_in_meters(overlay_handle)?,
+            color: self.color(overlay_handle)?,
+            alpha: self.alpha(overlay_handle)?,
+            sort_order: self.sort_order(overlay_handle)?,
+            curvature: self.curvature(overlay_handle)?,
+            pre_curve_pitch: self.pre_curve_pitch(overlay_handle)?,
+            texel_aspect: self.texel_aspect(overlay_handle)?,
+            stereo,
+            transform: self.transform(overlay_handle)?,
+        })
+    }
+
+    /// Applies all of the settings in `OverlayProperties`, in the order of its fields.
+    ///
+    /// Stops at the first error, leaving the overlay partly updated: the fields before the failing one have been
+    /// applied, and the rest have not. The transform is left unchanged if `properties.transform` is `None`.
+    pub fn set_properties(
+        &self,
+        overlay_handle: VROverlayHandle_t,
+        properties: &OverlayProperties,
+    ) -> Result<(), VROverlayError> {
+        let [red, green, blue] = properties.color;
+        self.set_width_in_meters(overlay_handle, properties.width_in_meters)?;
+        self.set_color(overlay_handle, red, green, blue)?;
+        self.set_alpha(overlay_handle, properties.alpha)?;
+        self.set_sort_order(overlay_handle, properties.sort_order)?;
+        self.set_curvature(overlay_handle, properties.curvature)?;
+        self.set_pre_curve_pitch(overlay_handle, properties.pre_curve_pitch)?;
+        self.set_texel_aspect(overlay_handle, properties.texel_aspect)?;
+        self.set_flag(
+            overlay_handle,
+            OverlayFlag::SideBySideParallel,
+            properties.stereo == StereoLayout::SideBySideParallel,
+        )?;
+        self.set_flag(
+            overlay_handle,
+            OverlayFlag::SideBySideCrossed,
+            properties.stereo == StereoLayout::SideBySideCrossed,
+        )?;
+        if let Some(ref transform) = properties.transform {
+            self.set_transform(overlay_handle, transform)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> OverlayHandle<'a> {
+    pub fn color(&self) -> Result<[f32; 3], VROverlayError> {
+        self.overlay.color(self.handle)
+    }
+
+    pub fn alpha(&self) -> Result<f32, VROverlayError> {
+        self.overlay.alpha(self.handle)
+    }
+
+    pub fn sort_order(&self) -> Result<u32, VROverlayError> {
+        self.overlay.sort_order(self.handle)
+    }
+
+    pub fn width_in_meters(&self) -> Result<f32, VROverlayError> {
+        self.overlay.width_in_meters(self.handle)
+    }
+
+    pub fn set_curvature(&self, curvature: f32) -> Result<(), VROverlayError> {
+        self.overlay.set_curvature(self.handle, curvature)
+    }
+
+    pub fn curvature(&self) -> Result<f32, VROverlayError> {
+        self.overlay.curvature(self.handle)
+    }
+
+    pub fn set_pre_curve_pitch(&self, radians: f32) -> Result<(), VROverlayError> {
+        self.overlay.set_pre_curve_pitch(self.handle, radians)
+    }
+
+    pub fn pre_curve_pitch(&self) -> Result<f32, VROverlayError> {
+        self.overlay.pre_curve_pitch(self.handle)
+    }
+
+    pub fn set_texel_aspect(&self, texel_aspect: f32) -> Result<(), VROverlayError> {
+        self.overlay.set_texel_aspect(self.handle, texel_aspect)
+    }
+
+    pub fn texel_aspect(&self) -> Result<f32, VROverlayError> {
+        self.overlay.texel_aspect(self.handle)
+    }
+
+    pub fn transform(&self) -> Result<Option<OverlayTransform>, VROverlayError> {
+        self.overlay.transform(self.handle)
+    }
+
+    pub fn set_transform(&self, transform: &OverlayTransform) -> Result<(), VROverlayError> {
+        self.overlay.set_transform(self.handle, transform)
+    }
+
+    pub fn properties(&self) -> Result<OverlayProperties, VROverlayError> {
+        self.overlay.properties(self.handle)
+    }
+
+    pub fn set_properties(&self, properties: &OverlayProperties) -> Result<(), VROverlayError> {
+        self.overlay.set_properties(self.handle, properties)
+    }
+}
+
+/// How the overlay texture is split between the eyes
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum StereoLayout {
+    /// Both eyes see the whole texture
+    Mono,
+    /// The left half of the texture is shown to the left eye
+    SideBySideParallel,
+    /// The left half of the texture is shown to the right eye
+    SideBySideCrossed,
+}
+
+/// Where an overlay is placed
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub enum OverlayTransform {
+    /// Fixed relative to a tracking origin
+    Absolute {
+        origin: TrackingUniverseOrigin,
+        origin_to_overlay: [[f32; 4]; 3],
+    },
+    /// Attached to a tracked device. Device indices are only stable while the device stays connected.
+    TrackedDeviceRelative {
+        device: TrackedDeviceIndex,
+        device_to_overlay: [[f32; 4]; 3],
+    },
+}
+
+/// The appearance and placement of an overlay, independent of its texture.
+///
+/// The defaults match the appearance of a newly created overlay, and leave its placement unchanged.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OverlayProperties {
+    pub width_in_meters: f32,
+    pub color: [f32; 3],
+    pub alpha: f32,
+    pub sort_order: u32,
+    pub curvature: f32,
+    pub pre_curve_pitch: f32,
+    pub texel_aspect: f32,
+    pub stereo: StereoLayout,
+    /// `None` for overlays placed in other ways, such as on a render model component
+    pub transform: Option<OverlayTransform>,
+}
+
+impl Default for OverlayProperties {
+    fn default() -> Self {
+        OverlayProperties {
+            width_in_meters: 1.0,
+            color: [1.0; 3],
+            alpha: 1.0,
+            sort_order: 0,
+            curvature: 0.0,
+            pre_curve_pitch: 0.0,
+            texel_aspect: 1.0,
+            stereo: StereoLayout::Mono,
+            transform: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn json_round_trip() {
+        let matrix = [
+            [1.0, 0.0, 0.0, 0.25],
+            [0.0, 0.0, -1.0, 1.5],
+            [0.0, 1.0, 0.0, -2.0],
+        ];
+        let layouts = [
+            OverlayProperties::default(),
+            OverlayProperties {
+                width_in_meters: 0.4,
+                color: [1.0, 0.5, 0.25],
+                alpha: 0.75,
+                sort_order: 3,
+                curvature: 0.1,
+                pre_curve_pitch: -0.2,
+                texel_aspect: 2.0,
+                stereo: StereoLayout::SideBySideCrossed,
+                transform: Some(OverlayTransform::Absolute {
+                    origin: TrackingUniverseOrigin::Standing,
+                    origin_to_overlay: matrix,
+                }),
+            },
+            OverlayProperties {
+                stereo: StereoLayout::SideBySideParallel,
+                transform: Some(OverlayTransform::TrackedDeviceRelative {
+                    device: 1,
+                    device_to_overlay: matrix,
+                }),
+                ..Default::default()
+            },
+        ];
+
+        let json = serde_json::to_string(&layouts).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Vec<OverlayProperties>>(&json).unwrap(),
+            layouts
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use std::{
+    error::Error,
+    ffi::{CStr, CString},
+    fmt::Display,
+    path::{Path, PathBuf},
+    ptr,
+};
+
+use openvr_sys as sys;
+use openvr_sys::{
+    EVRScreenshotError, EVRScreenshotError_VRScreenshotError_None, ScreenshotHandle_t,
+};
+
+use crate::Screenshots;
+
+#[derive(Debug)]
+pub struct VRScreenshotError(pub EVRScreenshotError);
+impl Display for VRScreenshotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EVRScreenshotError {}", self.0)
+    }
+}
+impl Error for VRScreenshotError {}
+impl From<EVRScreenshotError> for VRScreenshotError {
+    fn from(err: EVRScreenshotError) -> Self {
+        VRScreenshotError(err)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ScreenshotType {
+    Mono = sys::EVRScreenshotType_VRScreenshotType_Mono as isize,
+    Stereo = sys::EVRScreenshotType_VRScreenshotType_Stereo as isize,
+    Cubemap = sys::EVRScreenshotType_VRScreenshotType_Cubemap as isize,
+    MonoPanorama = sys::EVRScreenshotType_VRScreenshotType_MonoPanorama as isize,
+    StereoPanorama = sys::EVRScreenshotType_VRScreenshotType_StereoPanorama as isize,
+}
+
+impl ScreenshotType {
+    /// Returns `None` for `VRScreenshotType_None` and unknown types.
+    pub fn from_sys(ty: sys::EVRScreenshotType) -> Option<Self> {
+        use self::ScreenshotType::*;
+        Some(match ty {
+            sys::EVRScreenshotType_VRScreenshotType_Mono => Mono,
+            sys::EVRScreenshotType_VRScreenshotType_Stereo => Stereo,
+            sys::EVRScreenshotType_VRScreenshotType_Cubemap => Cubemap,
+            sys::EVRScreenshotType_VRScreenshotType_MonoPanorama => MonoPanorama,
+            sys::EVRScreenshotType_VRScreenshotType_StereoPanorama => StereoPanorama,
+            _ => return None,
+        })
+    }
+}
+
+/// Which of the two files of a screenshot to get the name of
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ScreenshotPropertyFilename {
+    /// The flat preview image
+    Preview = sys::EVRScreenshotPropertyFilenames_VRScreenshotPropertyFilenames_Preview as isize,
+    /// The full image in the format of its `ScreenshotType`
+    VR = sys::EVRScreenshotPropertyFilenames_VRScreenshotPropertyFilenames_VR as isize,
+}
+
+impl Screenshots {
+    /** Request a screenshot of the requested type.
+     * A request of the VRScreenshotType_Stereo type will always work. Other types will depend on the underlying
+     * application support.
+     * The first file name is for the preview image and should be a regular screenshot (ideally from the left eye).
+     * The second is the VR screenshot in the correct format. They should be in the same aspect ratio.
+     * Formats per type:
+     * VRScreenshotType_Mono: the VR filename is ignored (can be nullptr), this is a normal flat single shot.
+     * VRScreenshotType_Stereo: The VR image should be a side-by-side with the left eye image on the left.
+     * VRScreenshotType_Cubemap: The VR image should be six square images composited horizontally.
+     * VRScreenshotType_StereoPanorama: above/below with left eye panorama being the above image. Image is typically
+     * square with the panorama being 2x horizontal.
+     *
+     * Note that the VR dashboard will call this function when the user presses the screenshot binding (currently
+     * System Button + Trigger). If Steam is running, the destination file names will be in %TEMP% and will be
+     * copied into Steam's screenshot library for the running application once SubmitScreenshot() is called.
+     * If Steam is not running, the paths will be in the user's documents folder under Documents\SteamVR\Screenshots.
+     * Other VR applications can call this to initiate a screenshot outside of user control.
+     * The destination file names do not need an extension, will be replaced with the correct one for the format
+     * which is currently .png. */
+    pub fn request_screenshot(
+        &self,
+        ty: ScreenshotType,
+        preview_path: &Path,
+        vr_path: &Path,
+    ) -> Result<ScreenshotHandle_t, VRScreenshotError> {
+        let preview_path = CString::new(preview_path.to_string_lossy().as_bytes()).unwrap();
+        let vr_path = CString::new(vr_path.to_string_lossy().as_bytes()).unwrap();
+        let mut handle = sys::k_unScreenshotHandleInvalid;
+        let result = unsafe {
+            self.0.RequestScreenshot.unwrap()(
+                &mut handle,
+                ty as sys::EVRScreenshotType,
+                preview_path.as_ptr() as _,
+                vr_path.as_ptr() as _,
+            )
+        };
+        if result == EVRScreenshotError_VRScreenshotError_None {
+            Ok(handle)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Called by the running VR application to indicate that it wishes to be in charge of screenshots. If the
+     * application does not call this, the Compositor will only support VRScreenshotType_Stereo screenshots that will
+     * be captured without notification to the running app.
+     * Once hooked your application will receive a VREvent_RequestScreenshot event when the user presses the buttons
+     * to take a screenshot. */
+    pub fn hook_screenshot(
+        &self,
+        supported_types: &[ScreenshotType],
+    ) -> Result<(), VRScreenshotError> {
+        let mut types: Vec<sys::EVRScreenshotType> = supported_types
+            .iter()
+            .map(|&x| x as sys::EVRScreenshotType)
+            .collect();
+        let result =
+            unsafe { self.0.HookScreenshot.unwrap()(types.as_mut_ptr(), types.len() as _) };
+        if result == EVRScreenshotError_VRScreenshotError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** When your application receives a VREvent_RequestScreenshot event, call these functions to get the details of
+     * the screenshot request.
+     *
+     * Returns `None` if the screenshot has no type. */
+    pub fn get_screenshot_property_type(
+        &self,
+        screenshot_handle: ScreenshotHandle_t,
+    ) -> Result<Option<ScreenshotType>, VRScreenshotError> {
+        let mut error = EVRScreenshotError_VRScreenshotError_None;
+        let ty =
+            unsafe { self.0.GetScreenshotPropertyType.unwrap()(screenshot_handle, &mut error) };
+        if error == EVRScreenshotError_VRScreenshotError_None {
+            Ok(ScreenshotType::from_sys(ty))
+        } else {
+            Err(error.into())
+        }
+    }
+
+    /** Get the filename for the preview or vr image (see vr::EScreenshotPropertyFilenames). */
+    pub fn get_screenshot_property_filename(
+        &self,
+        screenshot_handle: ScreenshotHandle_t,
+        filename: ScreenshotPropertyFilename,
+    ) -> Result<PathBuf, VRScreenshotError> {
+        let mut error = EVRScreenshotError_VRScreenshotError_None;
+        let len = unsafe {
+            self.0.GetScreenshotPropertyFilename.unwrap()(
+                screenshot_handle,
+                filename as sys::EVRScreenshotPropertyFilenames,
+                ptr::null_mut(),
+                0,
+                &mut error,
+            )
+        };
+        if error != EVRScreenshotError_VRScreenshotError_None
+            && error != sys::EVRScreenshotError_VRScreenshotError_BufferTooSmall
+        {
+            return Err(error.into());
+        }
+
+        let mut buffer = vec![0; len.max(1) as usize];
+        error = EVRScreenshotError_VRScreenshotError_None;
+        unsafe {
+            self.0.GetScreenshotPropertyFilename.unwrap()(
+                screenshot_handle,
+                filename as sys::EVRScreenshotPropertyFilenames,
+                buffer.as_mut_ptr(),
+                buffer.len() as u32,
+                &mut error,
+            );
+        }
+        if error == EVRScreenshotError_VRScreenshotError_None {
+            let filename = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+            Ok(PathBuf::from(filename.to_string_lossy().into_owned()))
+        } else {
+            Err(error.into())
+        }
+    }
+
+    /** Call this if the application is taking the screen shot will take more than a few ms processing. This will
+     * result in an overlay being presented that shows a completion bar. */
+    pub fn update_screenshot_progress(
+        &self,
+        screenshot_handle: ScreenshotHandle_t,
+        progress: f32,
+    ) -> Result<(), VRScreenshotError> {
+        let result =
+            unsafe { self.0.UpdateScreenshotProgress.unwrap()(screenshot_handle, progress) };
+        if result == EVRScreenshotError_VRScreenshotError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Tells the compositor to take an internal screenshot of type VRScreenshotType_Stereo. It will take the current
+     * submitted scene textures of the running application and write them into the preview image and a side-by-side
+     * file for the VR image. This is similar to request screenshot, but doesn't ever talk to the application, just
+     * takes the shot and submits. */
+    pub fn take_stereo_screenshot(
+        &self,
+        preview_path: &Path,
+        vr_path: &Path,
+    ) -> Result<ScreenshotHandle_t, VRScreenshotError> {
+        let preview_path = CString::new(preview_path.to_string_lossy().as_bytes()).unwrap();
+        let vr_path = CString::new(vr_path.to_string_lossy().as_bytes()).unwrap();
+        let mut handle = sys::k_unScreenshotHandleInvalid;
+        let result = unsafe {
+            self.0.TakeStereoScreenshot.unwrap()(
+                &mut handle,
+                preview_path.as_ptr() as _,
+                vr_path.as_ptr() as _,
+            )
+        };
+        if result == EVRScreenshotError_VRScreenshotError_None {
+            Ok(handle)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Submit the completed screenshot. If Steam is running this will call into the Steam client and upload the
+     * screenshot to the screenshots section of the library for the running application. If Steam is not running,
+     * this function will display a notification to the user that the screenshot was taken. The paths should be full
+     * paths with extensions.
+     * File paths should be absolute including extensions.
+     * screenshotHandle can be k_unScreenshotHandleInvalid if this was a new shot taking by the app to be saved and
+     * not initiated by a user (achievement earned or something) */
+    pub fn submit_screenshot(
+        &self,
+        screenshot_handle: ScreenshotHandle_t,
+        ty: ScreenshotType,
+        preview_path: &Path,
+        vr_path: &Path,
+    ) -> Result<(), VRScreenshotError> {
+        let preview_path = CString::new(preview_path.to_string_lossy().as_bytes()).unwrap();
+        let vr_path = CString::new(vr_path.to_string_lossy().as_bytes()).unwrap();
+        let result = unsafe {
+            self.0.SubmitScreenshot.unwrap()(
+                screenshot_handle,
+                ty as sys::EVRScreenshotType,
+                preview_path.as_ptr() as _,
+                vr_path.as_ptr() as _,
+            )
+        };
+        if result == EVRScreenshotError_VRScreenshotError_None {
+            Ok(())
+        } else {
+            Err(result.into())
+        }
+    }
+}
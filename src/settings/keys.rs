@@ -0,0 +1,408 @@
+//! Typed keys for the settings of SteamVR and its bundled drivers.
+//!
+//! ```no_run
+//! # fn f(settings: &openvr::Settings) -> Result<(), openvr::settings::VRSettingsError> {
+//! use openvr::settings::keys;
+//! let scale = settings.get(keys::steamvr::SUPERSAMPLE_SCALE)?;
+//! settings.set(keys::steamvr::MOTION_SMOOTHING, false)?;
+//! # Ok(()) }
+//! ```
+
+/// General SteamVR settings
+pub mod steamvr {
+    use crate::settings::Key;
+    use openvr_sys as sys;
+
+    pub const REQUIRE_HMD: Key<String> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_RequireHmd_String,
+    );
+    pub const FORCED_DRIVER_KEY: Key<String> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_ForcedDriverKey_String,
+    );
+    pub const FORCED_HMD_KEY: Key<String> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_ForcedHmdKey_String,
+    );
+    pub const DISPLAY_DEBUG: Key<bool> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_DisplayDebug_Bool,
+    );
+    pub const DEBUG_PROCESS_PIPE: Key<String> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_DebugProcessPipe_String,
+    );
+    pub const DISPLAY_DEBUG_X: Key<i32> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_DisplayDebugX_Int32,
+    );
+    pub const DISPLAY_DEBUG_Y: Key<i32> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_DisplayDebugY_Int32,
+    );
+    pub const SEND_SYSTEM_BUTTON_TO_ALL_APPS: Key<bool> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_SendSystemButtonToAllApps_Bool,
+    );
+    pub const LOG_LEVEL: Key<i32> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_LogLevel_Int32,
+    );
+    pub const IPD: Key<f32> = Key::new(sys::k_pch_SteamVR_Section, sys::k_pch_SteamVR_IPD_Float);
+    pub const BACKGROUND: Key<String> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_Background_String,
+    );
+    pub const BACKGROUND_USE_DOME_PROJECTION: Key<bool> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_BackgroundUseDomeProjection_Bool,
+    );
+    pub const BACKGROUND_CAMERA_HEIGHT: Key<f32> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_BackgroundCameraHeight_Float,
+    );
+    pub const BACKGROUND_DOME_RADIUS: Key<f32> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_BackgroundDomeRadius_Float,
+    );
+    pub const GRID_COLOR: Key<String> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_GridColor_String,
+    );
+    pub const PLAY_AREA_COLOR: Key<String> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_PlayAreaColor_String,
+    );
+    pub const SHOW_STAGE: Key<bool> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_ShowStage_Bool,
+    );
+    pub const ACTIVATE_MULTIPLE_DRIVERS: Key<bool> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_ActivateMultipleDrivers_Bool,
+    );
+    pub const USING_SPEAKERS: Key<bool> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_UsingSpeakers_Bool,
+    );
+    pub const SPEAKERS_FORWARD_YAW_OFFSET_DEGREES: Key<f32> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_SpeakersForwardYawOffsetDegrees_Float,
+    );
+    pub const BASE_STATION_POWER_MANAGEMENT: Key<i32> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_BaseStationPowerManagement_Int32,
+    );
+    pub const NEVER_KILL_PROCESSES: Key<bool> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_NeverKillProcesses_Bool,
+    );
+    pub const SUPERSAMPLE_SCALE: Key<f32> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_SupersampleScale_Float,
+    );
+    pub const MAX_RECOMMENDED_RESOLUTION: Key<i32> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_MaxRecommendedResolution_Int32,
+    );
+    pub const MOTION_SMOOTHING: Key<bool> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_MotionSmoothing_Bool,
+    );
+    pub const MOTION_SMOOTHING_OVERRIDE: Key<i32> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_MotionSmoothingOverride_Int32,
+    );
+    pub const FORCE_FADE_ON_BAD_TRACKING: Key<bool> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_ForceFadeOnBadTracking_Bool,
+    );
+    pub const DEFAULT_MIRROR_VIEW: Key<i32> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_DefaultMirrorView_Int32,
+    );
+    pub const SHOW_MIRROR_VIEW: Key<bool> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_ShowMirrorView_Bool,
+    );
+    pub const MIRROR_VIEW_GEOMETRY: Key<String> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_MirrorViewGeometry_String,
+    );
+    pub const START_COMPOSITOR_FROM_APP_LAUNCH: Key<bool> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_StartCompositorFromAppLaunch_Bool,
+    );
+    pub const START_DASHBOARD_FROM_APP_LAUNCH: Key<bool> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_StartDashboardFromAppLaunch_Bool,
+    );
+    pub const START_OVERLAY_APPS_FROM_DASHBOARD: Key<bool> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_StartOverlayAppsFromDashboard_Bool,
+    );
+    pub const CYCLE_BACKGROUND_IMAGE_TIME_SEC: Key<i32> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_CycleBackgroundImageTimeSec_Int32,
+    );
+    pub const RETAIL_DEMO: Key<bool> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_RetailDemo_Bool,
+    );
+    pub const IPD_OFFSET: Key<f32> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_IpdOffset_Float,
+    );
+    pub const ALLOW_SUPERSAMPLE_FILTERING: Key<bool> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_AllowSupersampleFiltering_Bool,
+    );
+    pub const SUPERSAMPLE_MANUAL_OVERRIDE: Key<bool> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_SupersampleManualOverride_Bool,
+    );
+    pub const ENABLE_LINUX_VULKAN_ASYNC: Key<bool> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_EnableLinuxVulkanAsync_Bool,
+    );
+    pub const ALLOW_DISPLAY_LOCKED_MODE: Key<bool> = Key::new(
+        sys::k_pch_SteamVR_Section,
+        sys::k_pch_SteamVR_AllowDisplayLockedMode_Bool,
+    );
+}
+
+/// Settings of the Lighthouse tracking driver
+pub mod lighthouse {
+    use crate::settings::Key;
+    use openvr_sys as sys;
+
+    pub const DISABLE_IMU: Key<bool> = Key::new(
+        sys::k_pch_Lighthouse_Section,
+        sys::k_pch_Lighthouse_DisableIMU_Bool,
+    );
+    pub const DISABLE_IMU_EXCEPT_HMD: Key<bool> = Key::new(
+        sys::k_pch_Lighthouse_Section,
+        sys::k_pch_Lighthouse_DisableIMUExceptHMD_Bool,
+    );
+    pub const USE_DISAMBIGUATION: Key<String> = Key::new(
+        sys::k_pch_Lighthouse_Section,
+        sys::k_pch_Lighthouse_UseDisambiguation_String,
+    );
+    pub const DISAMBIGUATION_DEBUG: Key<i32> = Key::new(
+        sys::k_pch_Lighthouse_Section,
+        sys::k_pch_Lighthouse_DisambiguationDebug_Int32,
+    );
+    pub const PRIMARY_BASESTATION: Key<i32> = Key::new(
+        sys::k_pch_Lighthouse_Section,
+        sys::k_pch_Lighthouse_PrimaryBasestation_Int32,
+    );
+    pub const DB_HISTORY: Key<bool> = Key::new(
+        sys::k_pch_Lighthouse_Section,
+        sys::k_pch_Lighthouse_DBHistory_Bool,
+    );
+    pub const ENABLE_BLUETOOTH: Key<bool> = Key::new(
+        sys::k_pch_Lighthouse_Section,
+        sys::k_pch_Lighthouse_EnableBluetooth_Bool,
+    );
+    pub const POWER_MANAGED_BASE_STATIONS: Key<String> = Key::new(
+        sys::k_pch_Lighthouse_Section,
+        sys::k_pch_Lighthouse_PowerManagedBaseStations_String,
+    );
+    pub const POWER_MANAGED_BASE_STATIONS2: Key<String> = Key::new(
+        sys::k_pch_Lighthouse_Section,
+        sys::k_pch_Lighthouse_PowerManagedBaseStations2_String,
+    );
+    pub const INACTIVITY_TIMEOUT_FOR_BASE_STATIONS: Key<i32> = Key::new(
+        sys::k_pch_Lighthouse_Section,
+        sys::k_pch_Lighthouse_InactivityTimeoutForBaseStations_Int32,
+    );
+    pub const ENABLE_IMU_FALLBACK: Key<bool> = Key::new(
+        sys::k_pch_Lighthouse_Section,
+        sys::k_pch_Lighthouse_EnableImuFallback_Bool,
+    );
+}
+
+/// Settings of the null driver, a virtual HMD for testing without hardware
+pub mod null {
+    use crate::settings::Key;
+    use openvr_sys as sys;
+
+    pub const SERIAL_NUMBER: Key<String> =
+        Key::new(sys::k_pch_Null_Section, sys::k_pch_Null_SerialNumber_String);
+    pub const MODEL_NUMBER: Key<String> =
+        Key::new(sys::k_pch_Null_Section, sys::k_pch_Null_ModelNumber_String);
+    pub const WINDOW_X: Key<i32> = Key::new(sys::k_pch_Null_Section, sys::k_pch_Null_WindowX_Int32);
+    pub const WINDOW_Y: Key<i32> = Key::new(sys::k_pch_Null_Section, sys::k_pch_Null_WindowY_Int32);
+    pub const WINDOW_WIDTH: Key<i32> =
+        Key::new(sys::k_pch_Null_Section, sys::k_pch_Null_WindowWidth_Int32);
+    pub const WINDOW_HEIGHT: Key<i32> =
+        Key::new(sys::k_pch_Null_Section, sys::k_pch_Null_WindowHeight_Int32);
+    pub const RENDER_WIDTH: Key<i32> =
+        Key::new(sys::k_pch_Null_Section, sys::k_pch_Null_RenderWidth_Int32);
+    pub const RENDER_HEIGHT: Key<i32> =
+        Key::new(sys::k_pch_Null_Section, sys::k_pch_Null_RenderHeight_Int32);
+    pub const SECONDS_FROM_VSYNC_TO_PHOTONS: Key<f32> = Key::new(
+        sys::k_pch_Null_Section,
+        sys::k_pch_Null_SecondsFromVsyncToPhotons_Float,
+    );
+    pub const DISPLAY_FREQUENCY: Key<f32> = Key::new(
+        sys::k_pch_Null_Section,
+        sys::k_pch_Null_DisplayFrequency_Float,
+    );
+}
+
+/// Dashboard settings
+pub mod dashboard {
+    use crate::settings::Key;
+    use openvr_sys as sys;
+
+    pub const ENABLE_DASHBOARD: Key<bool> = Key::new(
+        sys::k_pch_Dashboard_Section,
+        sys::k_pch_Dashboard_EnableDashboard_Bool,
+    );
+    pub const ARCADE_MODE: Key<bool> = Key::new(
+        sys::k_pch_Dashboard_Section,
+        sys::k_pch_Dashboard_ArcadeMode_Bool,
+    );
+}
+
+/// Settings of the HMD front-facing camera
+pub mod camera {
+    use crate::settings::Key;
+    use openvr_sys as sys;
+
+    pub const ENABLE_CAMERA: Key<bool> = Key::new(
+        sys::k_pch_Camera_Section,
+        sys::k_pch_Camera_EnableCamera_Bool,
+    );
+    pub const ENABLE_CAMERA_IN_DASHBOARD: Key<bool> = Key::new(
+        sys::k_pch_Camera_Section,
+        sys::k_pch_Camera_EnableCameraInDashboard_Bool,
+    );
+    pub const ENABLE_CAMERA_FOR_COLLISION_BOUNDS: Key<bool> = Key::new(
+        sys::k_pch_Camera_Section,
+        sys::k_pch_Camera_EnableCameraForCollisionBounds_Bool,
+    );
+    pub const ENABLE_CAMERA_FOR_ROOM_VIEW: Key<bool> = Key::new(
+        sys::k_pch_Camera_Section,
+        sys::k_pch_Camera_EnableCameraForRoomView_Bool,
+    );
+    pub const BOUNDS_COLOR_GAMMA_R: Key<i32> = Key::new(
+        sys::k_pch_Camera_Section,
+        sys::k_pch_Camera_BoundsColorGammaR_Int32,
+    );
+    pub const BOUNDS_COLOR_GAMMA_G: Key<i32> = Key::new(
+        sys::k_pch_Camera_Section,
+        sys::k_pch_Camera_BoundsColorGammaG_Int32,
+    );
+    pub const BOUNDS_COLOR_GAMMA_B: Key<i32> = Key::new(
+        sys::k_pch_Camera_Section,
+        sys::k_pch_Camera_BoundsColorGammaB_Int32,
+    );
+    pub const BOUNDS_COLOR_GAMMA_A: Key<i32> = Key::new(
+        sys::k_pch_Camera_Section,
+        sys::k_pch_Camera_BoundsColorGammaA_Int32,
+    );
+    pub const BOUNDS_STRENGTH: Key<i32> = Key::new(
+        sys::k_pch_Camera_Section,
+        sys::k_pch_Camera_BoundsStrength_Int32,
+    );
+}
+
+/// Audio device switching when the HMD is in use
+pub mod audio {
+    use crate::settings::Key;
+    use openvr_sys as sys;
+
+    pub const ON_PLAYBACK_DEVICE: Key<String> = Key::new(
+        sys::k_pch_audio_Section,
+        sys::k_pch_audio_OnPlaybackDevice_String,
+    );
+    pub const ON_RECORD_DEVICE: Key<String> = Key::new(
+        sys::k_pch_audio_Section,
+        sys::k_pch_audio_OnRecordDevice_String,
+    );
+    pub const ON_PLAYBACK_MIRROR_DEVICE: Key<String> = Key::new(
+        sys::k_pch_audio_Section,
+        sys::k_pch_audio_OnPlaybackMirrorDevice_String,
+    );
+    pub const OFF_PLAYBACK_DEVICE: Key<String> = Key::new(
+        sys::k_pch_audio_Section,
+        sys::k_pch_audio_OffPlaybackDevice_String,
+    );
+    pub const OFF_RECORD_DEVICE: Key<String> = Key::new(
+        sys::k_pch_audio_Section,
+        sys::k_pch_audio_OffRecordDevice_String,
+    );
+}
+
+/// Power management settings
+pub mod power {
+    use crate::settings::Key;
+    use openvr_sys as sys;
+
+    pub const POWER_OFF_ON_EXIT: Key<bool> = Key::new(
+        sys::k_pch_Power_Section,
+        sys::k_pch_Power_PowerOffOnExit_Bool,
+    );
+    pub const TURN_OFF_SCREENS_TIMEOUT: Key<f32> = Key::new(
+        sys::k_pch_Power_Section,
+        sys::k_pch_Power_TurnOffScreensTimeout_Float,
+    );
+    pub const TURN_OFF_CONTROLLERS_TIMEOUT: Key<f32> = Key::new(
+        sys::k_pch_Power_Section,
+        sys::k_pch_Power_TurnOffControllersTimeout_Float,
+    );
+    pub const RETURN_TO_WATCHDOG_TIMEOUT: Key<f32> = Key::new(
+        sys::k_pch_Power_Section,
+        sys::k_pch_Power_ReturnToWatchdogTimeout_Float,
+    );
+    pub const PAUSE_COMPOSITOR_ON_STANDBY: Key<bool> = Key::new(
+        sys::k_pch_Power_Section,
+        sys::k_pch_Power_PauseCompositorOnStandby_Bool,
+    );
+}
+
+/// Chaperone appearance settings
+pub mod collision_bounds {
+    use crate::settings::Key;
+    use openvr_sys as sys;
+
+    pub const STYLE: Key<i32> = Key::new(
+        sys::k_pch_CollisionBounds_Section,
+        sys::k_pch_CollisionBounds_Style_Int32,
+    );
+    pub const GROUND_PERIMETER_ON: Key<bool> = Key::new(
+        sys::k_pch_CollisionBounds_Section,
+        sys::k_pch_CollisionBounds_GroundPerimeterOn_Bool,
+    );
+    pub const CENTER_MARKER_ON: Key<bool> = Key::new(
+        sys::k_pch_CollisionBounds_Section,
+        sys::k_pch_CollisionBounds_CenterMarkerOn_Bool,
+    );
+    pub const PLAY_SPACE_ON: Key<bool> = Key::new(
+        sys::k_pch_CollisionBounds_Section,
+        sys::k_pch_CollisionBounds_PlaySpaceOn_Bool,
+    );
+    pub const FADE_DISTANCE: Key<f32> = Key::new(
+        sys::k_pch_CollisionBounds_Section,
+        sys::k_pch_CollisionBounds_FadeDistance_Float,
+    );
+    pub const COLOR_GAMMA_R: Key<i32> = Key::new(
+        sys::k_pch_CollisionBounds_Section,
+        sys::k_pch_CollisionBounds_ColorGammaR_Int32,
+    );
+    pub const COLOR_GAMMA_G: Key<i32> = Key::new(
+        sys::k_pch_CollisionBounds_Section,
+        sys::k_pch_CollisionBounds_ColorGammaG_Int32,
+    );
+    pub const COLOR_GAMMA_B: Key<i32> = Key::new(
+        sys::k_pch_CollisionBounds_Section,
+        sys::k_pch_CollisionBounds_ColorGammaB_Int32,
+    );
+    pub const COLOR_GAMMA_A: Key<i32> = Key::new(
+        sys::k_pch_CollisionBounds_Section,
+        sys::k_pch_CollisionBounds_ColorGammaA_Int32,
+    );
+}
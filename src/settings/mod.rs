@@ -0,0 +1,363 @@
+use std::{
+    error::Error,
+    ffi::{CStr, CString},
+    fmt::Display,
+    marker::PhantomData,
+};
+
+use openvr_sys::{EVRSettingsError, EVRSettingsError_VRSettingsError_None};
+
+use crate::Settings;
+
+pub mod keys;
+pub mod snapshot;
+
+pub use self::snapshot::{AnyValue, SettingChange, SettingsSnapshot};
+
+/// An error from `Settings`, along with its name as reported by OpenVR
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct VRSettingsError {
+    pub code: EVRSettingsError,
+    /// e.g. "VRSettingsError_ReadFailed"
+    pub name: String,
+}
+impl Display for VRSettingsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.name.is_empty() {
+            write!(f, "EVRSettingsError {}", self.code)
+        } else {
+            f.write_str(&self.name)
+        }
+    }
+}
+impl Error for VRSettingsError {}
+
+/// A setting whose value has type `T`
+pub struct Key<T> {
+    section: &'static [u8],
+    key: &'static [u8],
+    _ty: PhantomData<fn() -> T>,
+}
+
+impl<T> Key<T> {
+    /// Section and key names must be nul-terminated ASCII, like the `k_pch_*` constants.
+    ///
+    /// # Panics
+    ///
+    /// If either name is not nul-terminated ASCII. In a `const` this is a compile error.
+    pub const fn new(section: &'static [u8], key: &'static [u8]) -> Self {
+        assert!(
+            is_nul_terminated_ascii(section),
+            "section name must be nul-terminated ASCII"
+        );
+        assert!(
+            is_nul_terminated_ascii(key),
+            "key name must be nul-terminated ASCII"
+        );
+        Key {
+            section,
+            key,
+            _ty: PhantomData,
+        }
+    }
+
+    pub fn section(&self) -> &'static str {
+        CStr::from_bytes_with_nul(self.section)
+            .unwrap()
+            .to_str()
+            .unwrap()
+    }
+
+    pub fn key(&self) -> &'static str {
+        CStr::from_bytes_with_nul(self.key)
+            .unwrap()
+            .to_str()
+            .unwrap()
+    }
+}
+
+const fn is_nul_terminated_ascii(name: &[u8]) -> bool {
+    if name.is_empty() || name[name.len() - 1] != 0 {
+        return false;
+    }
+    let mut i = 0;
+    while i < name.len() - 1 {
+        if name[i] == 0 || !name[i].is_ascii() {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+impl<T> Clone for Key<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for Key<T> {}
+
+impl<T> std::fmt::Debug for Key<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Key({}.{})", self.section(), self.key())
+    }
+}
+
+/// A type that settings can be read as and written from
+pub trait SettingValue: Sized {
+    fn get(settings: &Settings, section: &str, key: &str) -> Result<Self, VRSettingsError>;
+    fn set(&self, settings: &Settings, section: &str, key: &str) -> Result<(), VRSettingsError>;
+    fn into_any(self) -> AnyValue;
+}
+
+impl SettingValue for bool {
+    fn get(settings: &Settings, section: &str, key: &str) -> Result<Self, VRSettingsError> {
+        settings.get_bool(section, key)
+    }
+    fn set(&self, settings: &Settings, section: &str, key: &str) -> Result<(), VRSettingsError> {
+        settings.set_bool(section, key, *self)
+    }
+    fn into_any(self) -> AnyValue {
+        AnyValue::Bool(self)
+    }
+}
+
+impl SettingValue for i32 {
+    fn get(settings: &Settings, section: &str, key: &str) -> Result<Self, VRSettingsError> {
+        settings.get_i32(section, key)
+    }
+    fn set(&self, settings: &Settings, section: &str, key: &str) -> Result<(), VRSettingsError> {
+        settings.set_i32(section, key, *self)
+    }
+    fn into_any(self) -> AnyValue {
+        AnyValue::Int32(self)
+    }
+}
+
+impl SettingValue for f32 {
+    fn get(settings: &Settings, section: &str, key: &str) -> Result<Self, VRSettingsError> {
+        settings.get_f32(section, key)
+    }
+    fn set(&self, settings: &Settings, section: &str, key: &str) -> Result<(), VRSettingsError> {
+        settings.set_f32(section, key, *self)
+    }
+    fn into_any(self) -> AnyValue {
+        AnyValue::Float(self)
+    }
+}
+
+impl SettingValue for String {
+    fn get(settings: &Settings, section: &str, key: &str) -> Result<Self, VRSettingsError> {
+        settings.get_string(section, key)
+    }
+    fn set(&self, settings: &Settings, section: &str, key: &str) -> Result<(), VRSettingsError> {
+        settings.set_string(section, key, self)
+    }
+    fn into_any(self) -> AnyValue {
+        AnyValue::String(self)
+    }
+}
+
+impl Settings {
+    /// Reads a setting from the `keys` catalogue, or any other typed `Key`.
+    pub fn get<T: SettingValue>(&self, key: Key<T>) -> Result<T, VRSettingsError> {
+        T::get(self, key.section(), key.key())
+    }
+
+    pub fn set<T: SettingValue>(
+        &self,
+        key: Key<T>,
+        value: impl Into<T>,
+    ) -> Result<(), VRSettingsError> {
+        value.into().set(self, key.section(), key.key())
+    }
+
+    /// Removes a setting, restoring its default value.
+    pub fn remove<T>(&self, key: Key<T>) -> Result<(), VRSettingsError> {
+        self.remove_key_in_section(key.section(), key.key())
+    }
+
+    /// Builds a `VRSettingsError`, looking up its name with `GetSettingsErrorNameFromEnum`.
+    fn error(&self, code: EVRSettingsError) -> VRSettingsError {
+        let name = unsafe { self.0.GetSettingsErrorNameFromEnum.unwrap()(code) };
+        let name = if name.is_null() {
+            String::new()
+        } else {
+            unsafe { CStr::from_ptr(name).to_string_lossy().into_owned() }
+        };
+        VRSettingsError { code, name }
+    }
+
+    pub fn set_bool(&self, section: &str, key: &str, value: bool) -> Result<(), VRSettingsError> {
+        let section = CString::new(section).unwrap();
+        let key = CString::new(key).unwrap();
+        let mut error = EVRSettingsError_VRSettingsError_None;
+        unsafe {
+            self.0.SetBool.unwrap()(section.as_ptr() as _, key.as_ptr() as _, value, &mut error)
+        };
+        if error == EVRSettingsError_VRSettingsError_None {
+            Ok(())
+        } else {
+            Err(self.error(error))
+        }
+    }
+
+    pub fn set_i32(&self, section: &str, key: &str, value: i32) -> Result<(), VRSettingsError> {
+        let section = CString::new(section).unwrap();
+        let key = CString::new(key).unwrap();
+        let mut error = EVRSettingsError_VRSettingsError_None;
+        unsafe {
+            self.0.SetInt32.unwrap()(section.as_ptr() as _, key.as_ptr() as _, value, &mut error)
+        };
+        if error == EVRSettingsError_VRSettingsError_None {
+            Ok(())
+        } else {
+            Err(self.error(error))
+        }
+    }
+
+    pub fn set_f32(&self, section: &str, key: &str, value: f32) -> Result<(), VRSettingsError> {
+        let section = CString::new(section).unwrap();
+        let key = CString::new(key).unwrap();
+        let mut error = EVRSettingsError_VRSettingsError_None;
+        unsafe {
+            self.0.SetFloat.unwrap()(section.as_ptr() as _, key.as_ptr() as _, value, &mut error)
+        };
+        if error == EVRSettingsError_VRSettingsError_None {
+            Ok(())
+        } else {
+            Err(self.error(error))
+        }
+    }
+
+    pub fn set_string(&self, section: &str, key: &str, value: &str) -> Result<(), VRSettingsError> {
+        let section = CString::new(section).unwrap();
+        let key = CString::new(key).unwrap();
+        let value = CString::new(value).unwrap();
+        let mut error = EVRSettingsError_VRSettingsError_None;
+        unsafe {
+            self.0.SetString.unwrap()(
+                section.as_ptr() as _,
+                key.as_ptr() as _,
+                value.as_ptr() as _,
+                &mut error,
+            )
+        };
+        if error == EVRSettingsError_VRSettingsError_None {
+            Ok(())
+        } else {
+            Err(self.error(error))
+        }
+    }
+
+    /** Users of the system need to provide a proper default in default.vrsettings in the resources/settings/ directory
+     * of either the runtime or the driver_xxx directory. Otherwise the default will be false, 0, 0.0 or "" */
+    pub fn get_bool(&self, section: &str, key: &str) -> Result<bool, VRSettingsError> {
+        let section = CString::new(section).unwrap();
+        let key = CString::new(key).unwrap();
+        let mut error = EVRSettingsError_VRSettingsError_None;
+        let value = unsafe {
+            self.0.GetBool.unwrap()(section.as_ptr() as _, key.as_ptr() as _, &mut error)
+        };
+        if error == EVRSettingsError_VRSettingsError_None {
+            Ok(value)
+        } else {
+            Err(self.error(error))
+        }
+    }
+
+    pub fn get_i32(&self, section: &str, key: &str) -> Result<i32, VRSettingsError> {
+        let section = CString::new(section).unwrap();
+        let key = CString::new(key).unwrap();
+        let mut error = EVRSettingsError_VRSettingsError_None;
+        let value = unsafe {
+            self.0.GetInt32.unwrap()(section.as_ptr() as _, key.as_ptr() as _, &mut error)
+        };
+        if error == EVRSettingsError_VRSettingsError_None {
+            Ok(value)
+        } else {
+            Err(self.error(error))
+        }
+    }
+
+    pub fn get_f32(&self, section: &str, key: &str) -> Result<f32, VRSettingsError> {
+        let section = CString::new(section).unwrap();
+        let key = CString::new(key).unwrap();
+        let mut error = EVRSettingsError_VRSettingsError_None;
+        let value = unsafe {
+            self.0.GetFloat.unwrap()(section.as_ptr() as _, key.as_ptr() as _, &mut error)
+        };
+        if error == EVRSettingsError_VRSettingsError_None {
+            Ok(value)
+        } else {
+            Err(self.error(error))
+        }
+    }
+
+    pub fn get_string(&self, section: &str, key: &str) -> Result<String, VRSettingsError> {
+        let section = CString::new(section).unwrap();
+        let key = CString::new(key).unwrap();
+        let mut error = EVRSettingsError_VRSettingsError_None;
+        let mut buffer = vec![0; 4096];
+        unsafe {
+            self.0.GetString.unwrap()(
+                section.as_ptr() as _,
+                key.as_ptr() as _,
+                buffer.as_mut_ptr(),
+                buffer.len() as u32,
+                &mut error,
+            );
+            if error == EVRSettingsError_VRSettingsError_None {
+                Ok(CStr::from_ptr(buffer.as_ptr())
+                    .to_string_lossy()
+                    .into_owned())
+            } else {
+                Err(self.error(error))
+            }
+        }
+    }
+
+    pub fn remove_section(&self, section: &str) -> Result<(), VRSettingsError> {
+        let section = CString::new(section).unwrap();
+        let mut error = EVRSettingsError_VRSettingsError_None;
+        unsafe { self.0.RemoveSection.unwrap()(section.as_ptr() as _, &mut error) };
+        if error == EVRSettingsError_VRSettingsError_None {
+            Ok(())
+        } else {
+            Err(self.error(error))
+        }
+    }
+
+    pub fn remove_key_in_section(&self, section: &str, key: &str) -> Result<(), VRSettingsError> {
+        let section = CString::new(section).unwrap();
+        let key = CString::new(key).unwrap();
+        let mut error = EVRSettingsError_VRSettingsError_None;
+        unsafe {
+            self.0.RemoveKeyInSection.unwrap()(section.as_ptr() as _, key.as_ptr() as _, &mut error)
+        };
+        if error == EVRSettingsError_VRSettingsError_None {
+            Ok(())
+        } else {
+            Err(self.error(error))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn key_names() {
+        const SCALE: Key<f32> = Key::new(b"steamvr\0", b"supersampleScale\0");
+        assert_eq!(SCALE.section(), "steamvr");
+        assert_eq!(SCALE.key(), "supersampleScale");
+        assert_eq!(format!("{:?}", SCALE), "Key(steamvr.supersampleScale)");
+    }
+
+    #[test]
+    #[should_panic(expected = "key name must be nul-terminated ASCII")]
+    fn key_without_nul() {
+        Key::<f32>::new(b"steamvr\0", b"supersampleScale");
+    }
+}
@@ -0,0 +1,228 @@
+//! Saving the values of a set of settings so they can be compared and restored later.
+
+use std::collections::BTreeMap;
+
+use openvr_sys::EVRSettingsError_VRSettingsError_UnsetSettingHasNoDefault;
+use serde::{Deserialize, Serialize};
+
+use super::{Key, SettingValue, VRSettingsError};
+use crate::Settings;
+
+/// The value of a setting of any type
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AnyValue {
+    Bool(bool),
+    Int32(i32),
+    Float(f32),
+    String(String),
+}
+
+/// The values of a set of settings at some point in time.
+///
+/// Settings that had no value and no default when captured are recorded as `None`, and are removed again by
+/// `SettingsSnapshot::restore`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SettingsSnapshot {
+    /// Values by section, then by key
+    pub sections: BTreeMap<String, BTreeMap<String, Option<AnyValue>>>,
+}
+
+/// A setting whose value differs between two snapshots
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingChange {
+    pub section: String,
+    pub key: String,
+    pub old: Option<AnyValue>,
+    pub new: Option<AnyValue>,
+}
+
+impl SettingsSnapshot {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Records the current value of a setting, replacing any earlier value for it.
+    ///
+    /// OpenVR doesn't report whether a value was set by the user or is the default, so a setting that only has its
+    /// default is recorded as that value, and `restore` will write it to the user's settings. Only settings that have
+    /// neither a value nor a default are recorded as `None`.
+    pub fn capture<T: SettingValue>(
+        &mut self,
+        settings: &Settings,
+        key: Key<T>,
+    ) -> Result<(), VRSettingsError> {
+        let value = match settings.get(key) {
+            Ok(x) => Some(x.into_any()),
+            Err(e) if e.code == EVRSettingsError_VRSettingsError_UnsetSettingHasNoDefault => None,
+            Err(e) => return Err(e),
+        };
+        self.insert(key.section(), key.key(), value);
+        Ok(())
+    }
+
+    pub fn insert(&mut self, section: &str, key: &str, value: Option<AnyValue>) {
+        self.sections
+            .entry(section.to_owned())
+            .or_default()
+            .insert(key.to_owned(), value);
+    }
+
+    pub fn get(&self, section: &str, key: &str) -> Option<&Option<AnyValue>> {
+        self.sections.get(section)?.get(key)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str, Option<&AnyValue>)> {
+        self.sections.iter().flat_map(|(section, keys)| {
+            keys.iter()
+                .map(move |(key, value)| (section.as_str(), key.as_str(), value.as_ref()))
+        })
+    }
+
+    /// Lists the settings that changed from `self` to `new`, ordered by section and key.
+    ///
+    /// A setting captured in only one of the snapshots is compared as if it were unset in the other.
+    pub fn diff(&self, new: &SettingsSnapshot) -> Vec<SettingChange> {
+        let mut changes = Vec::new();
+        for (section, key, old_value) in self.iter() {
+            let new_value = new.get(section, key).and_then(|x| x.as_ref());
+            if old_value != new_value {
+                changes.push(SettingChange {
+                    section: section.to_owned(),
+                    key: key.to_owned(),
+                    old: old_value.cloned(),
+                    new: new_value.cloned(),
+                });
+            }
+        }
+        for (section, key, new_value) in new.iter() {
+            if self.get(section, key).is_none() && new_value.is_some() {
+                changes.push(SettingChange {
+                    section: section.to_owned(),
+                    key: key.to_owned(),
+                    old: None,
+                    new: new_value.cloned(),
+                });
+            }
+        }
+        changes.sort_by(|a, b| (&a.section, &a.key).cmp(&(&b.section, &b.key)));
+        changes
+    }
+
+    /// Writes every captured value back, and removes the settings that were unset.
+    ///
+    /// Every setting is attempted even if some fail. The failures are returned as `(section, key, error)`, ordered by
+    /// section and key. Settings that only had their default when captured are written explicitly, pinning them to
+    /// that value even if the default changes later; see `capture`.
+    pub fn restore(
+        &self,
+        settings: &Settings,
+    ) -> Result<(), Vec<(String, String, VRSettingsError)>> {
+        let mut failures = Vec::new();
+        for (section, key, value) in self.iter() {
+            let result = match value {
+                Some(AnyValue::Bool(x)) => settings.set_bool(section, key, *x),
+                Some(AnyValue::Int32(x)) => settings.set_i32(section, key, *x),
+                Some(AnyValue::Float(x)) => settings.set_f32(section, key, *x),
+                Some(AnyValue::String(x)) => settings.set_string(section, key, x),
+                None => settings.remove_key_in_section(section, key),
+            };
+            if let Err(e) = result {
+                failures.push((section.to_owned(), key.to_owned(), e));
+            }
+        }
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(failures)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn diff() {
+        let mut old = SettingsSnapshot::new();
+        old.insert("steamvr", "supersampleScale", Some(AnyValue::Float(1.0)));
+        old.insert("steamvr", "motionSmoothing", Some(AnyValue::Bool(true)));
+        old.insert("power", "pauseCompositorOnStandby", None);
+        old.insert("dashboard", "arcadeMode", None);
+
+        let mut new = old.clone();
+        assert_eq!(old.diff(&new), vec![]);
+
+        new.insert("steamvr", "supersampleScale", Some(AnyValue::Float(1.5)));
+        new.insert(
+            "power",
+            "pauseCompositorOnStandby",
+            Some(AnyValue::Bool(false)),
+        );
+        new.sections
+            .get_mut("steamvr")
+            .unwrap()
+            .remove("motionSmoothing");
+        new.insert(
+            "audio",
+            "onPlaybackDevice",
+            Some(AnyValue::String("hmd".into())),
+        );
+        new.insert("camera", "enableCamera", None);
+
+        let change = |section: &str, key: &str, old, new| SettingChange {
+            section: section.into(),
+            key: key.into(),
+            old,
+            new,
+        };
+        assert_eq!(
+            old.diff(&new),
+            vec![
+                change(
+                    "audio",
+                    "onPlaybackDevice",
+                    None,
+                    Some(AnyValue::String("hmd".into()))
+                ),
+                change(
+                    "power",
+                    "pauseCompositorOnStandby",
+                    None,
+                    Some(AnyValue::Bool(false))
+                ),
+                change(
+                    "steamvr",
+                    "motionSmoothing",
+                    Some(AnyValue::Bool(true)),
+                    None
+                ),
+                change(
+                    "steamvr",
+                    "supersampleScale",
+                    Some(AnyValue::Float(1.0)),
+                    Some(AnyValue::Float(1.5))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let mut snapshot = SettingsSnapshot::new();
+        snapshot.insert("steamvr", "supersampleScale", Some(AnyValue::Float(1.25)));
+        snapshot.insert("steamvr", "logLevel", Some(AnyValue::Int32(2)));
+        snapshot.insert(
+            "driver_null",
+            "serialNumber",
+            Some(AnyValue::String("x".into())),
+        );
+        snapshot.insert("power", "powerOffOnExit", None);
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert_eq!(
+            serde_json::from_str::<SettingsSnapshot>(&json).unwrap(),
+            snapshot
+        );
+    }
+}
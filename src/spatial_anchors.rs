@@ -0,0 +1,157 @@
+//! Spatial anchors pin content to a place in the physical world.
+//!
+//! An anchor's descriptor is a string that can be saved and used to recreate the anchor in a later session. The
+//! driver may update the pose and descriptor of an anchor at any time, which is signalled with
+//! `Event::SpatialAnchors_PoseUpdated` and `Event::SpatialAnchors_DescriptorUpdated`.
+
+use std::{
+    error::Error,
+    ffi::{CStr, CString},
+    fmt::Display,
+};
+
+use openvr_sys as sys;
+use openvr_sys::{
+    EVRSpatialAnchorError, EVRSpatialAnchorError_VRSpatialAnchorError_Success,
+    SpatialAnchorHandle_t,
+};
+
+use crate::{SpatialAnchors, TrackedDeviceIndex, TrackingUniverseOrigin};
+
+#[derive(Debug)]
+pub struct VRSpatialAnchorError(pub EVRSpatialAnchorError);
+impl Display for VRSpatialAnchorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EVRSpatialAnchorError {}", self.0)
+    }
+}
+impl Error for VRSpatialAnchorError {}
+impl From<EVRSpatialAnchorError> for VRSpatialAnchorError {
+    fn from(err: EVRSpatialAnchorError) -> Self {
+        VRSpatialAnchorError(err)
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct SpatialAnchorPose(sys::SpatialAnchorPose_t);
+
+impl SpatialAnchorPose {
+    pub fn new(anchor_to_absolute_tracking: [[f32; 4]; 3]) -> Self {
+        SpatialAnchorPose(sys::SpatialAnchorPose_t {
+            mAnchorToAbsoluteTracking: sys::HmdMatrix34_t {
+                m: anchor_to_absolute_tracking,
+            },
+        })
+    }
+
+    pub fn anchor_to_absolute_tracking(&self) -> &[[f32; 4]; 3] {
+        &self.0.mAnchorToAbsoluteTracking.m
+    }
+}
+
+impl From<sys::SpatialAnchorPose_t> for SpatialAnchorPose {
+    fn from(x: sys::SpatialAnchorPose_t) -> Self {
+        SpatialAnchorPose(x)
+    }
+}
+
+impl SpatialAnchors {
+    /** Returns a handle for an spatial anchor described by "descriptor". On success, pHandle will contain a handle
+     * valid for this session. Caller can wait for an event or occasionally poll GetSpatialAnchorPose() to find the
+     * virtual coordinate associated with this anchor. */
+    pub fn create_from_descriptor(
+        &self,
+        descriptor: &str,
+    ) -> Result<SpatialAnchorHandle_t, VRSpatialAnchorError> {
+        let descriptor = CString::new(descriptor).unwrap();
+        let mut handle = sys::k_ulInvalidSpatialAnchorHandle;
+        let result = unsafe {
+            self.0.CreateSpatialAnchorFromDescriptor.unwrap()(descriptor.as_ptr() as _, &mut handle)
+        };
+        if result == EVRSpatialAnchorError_VRSpatialAnchorError_Success {
+            Ok(handle)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Returns a handle for an new spatial anchor at pPose. On success, pHandle will contain a handle valid for this
+     * session. Caller can wait for an event or occasionally poll GetSpatialAnchorDescriptor() to find the permanent
+     * descriptor for this pose. The result of GetSpatialAnchorPose() may evolve from this initial position if the
+     * driver chooses to update it. The anchor will be associated with the driver that provides unDeviceIndex, and the
+     * driver may use that specific device as a hint for how to best create the anchor. The eOrigin must match
+     * whatever tracking origin you are working in (seated/standing/raw). This should be called when the user is
+     * close to (and ideally looking at/interacting with) the target physical location. At that moment, the driver
+     * will have the most information about how to recover that physical point in the future, and the quality of the
+     * anchor (when the descriptor is re-used) will be highest. The caller may decide to apply offsets from this
+     * initial pose, but is advised to stay relatively close to the original pose location for highest fidelity. */
+    pub fn create_from_pose(
+        &self,
+        device_index: TrackedDeviceIndex,
+        origin: TrackingUniverseOrigin,
+        pose: &SpatialAnchorPose,
+    ) -> Result<SpatialAnchorHandle_t, VRSpatialAnchorError> {
+        let mut pose = pose.0;
+        let mut handle = sys::k_ulInvalidSpatialAnchorHandle;
+        let result = unsafe {
+            self.0.CreateSpatialAnchorFromPose.unwrap()(
+                device_index,
+                origin as sys::ETrackingUniverseOrigin,
+                &mut pose,
+                &mut handle,
+            )
+        };
+        if result == EVRSpatialAnchorError_VRSpatialAnchorError_Success {
+            Ok(handle)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Get the pose for a given handle. This is intended to be cheap enough to call every frame (or fairly often) so
+     * that the driver can refine this position when it has more information available. */
+    pub fn get_pose(
+        &self,
+        handle: SpatialAnchorHandle_t,
+        origin: TrackingUniverseOrigin,
+    ) -> Result<SpatialAnchorPose, VRSpatialAnchorError> {
+        let mut pose = SpatialAnchorPose::new([[0.0; 4]; 3]);
+        let result = unsafe {
+            self.0.GetSpatialAnchorPose.unwrap()(
+                handle,
+                origin as sys::ETrackingUniverseOrigin,
+                &mut pose.0,
+            )
+        };
+        if result == EVRSpatialAnchorError_VRSpatialAnchorError_Success {
+            Ok(pose)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Get the descriptor for a given handle. This will be empty for handles where the driver has not yet built a
+     * descriptor. It will be the application-supplied descriptor for previously saved anchors that the application
+     * is requesting poses for. If the driver has called UpdateSpatialAnchorDescriptor() already in this session, it
+     * will be the descriptor provided by the driver. */
+    pub fn get_descriptor(
+        &self,
+        handle: SpatialAnchorHandle_t,
+    ) -> Result<String, VRSpatialAnchorError> {
+        let mut buffer = vec![0; sys::k_unMaxSpatialAnchorDescriptorSize as usize];
+        let mut len = buffer.len() as u32;
+        let result = unsafe {
+            self.0.GetSpatialAnchorDescriptor.unwrap()(handle, buffer.as_mut_ptr(), &mut len)
+        };
+        if result == EVRSpatialAnchorError_VRSpatialAnchorError_Success {
+            unsafe {
+                Ok(CStr::from_ptr(buffer.as_ptr())
+                    .to_string_lossy()
+                    .into_owned())
+            }
+        } else {
+            Err(result.into())
+        }
+    }
+}
@@ -27,6 +27,7 @@ trait FromEventData {
 }
 
 use super::*;
+use crate::screenshots::ScreenshotType;
 
 #[derive(Debug, Copy, Clone)]
 /// Controller button events
@@ -110,6 +111,15 @@ pub struct Notification {
     pub notification_id: u32,
 }
 
+impl FromEventData for Notification {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        Notification {
+            user_value: x.notification.ulUserValue,
+            notification_id: x.notification.notificationId,
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct Process {
     pub pid: u32,
@@ -147,10 +157,32 @@ pub struct Status {
 
 #[derive(Debug, Copy, Clone)]
 pub struct Keyboard {
+    /// Nul-terminated UTF-8. Use `text` or `char` to decode it.
     pub new_input: [u8; 8],
+    /// The value passed to `Overlay::show_keyboard`
     pub user_value: u64,
 }
 
+impl Keyboard {
+    /// The text entered since the previous event, usually a single character
+    pub fn text(&self) -> &str {
+        let len = self
+            .new_input
+            .iter()
+            .position(|&b| b == 0)
+            .unwrap_or(self.new_input.len());
+        match std::str::from_utf8(&self.new_input[..len]) {
+            Ok(x) => x,
+            Err(e) => std::str::from_utf8(&self.new_input[..e.valid_up_to()]).unwrap(),
+        }
+    }
+
+    /// The first character of `text`, if any
+    pub fn char(&self) -> Option<char> {
+        self.text().chars().next()
+    }
+}
+
 impl FromEventData for Keyboard {
     unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
         let x = &*(&x.keyboard as *const _ as *const sys::VREvent_Keyboard_t_real);
@@ -188,6 +220,48 @@ impl FromEventData for Property {
     }
 }
 
+#[derive(Debug, Copy, Clone)]
+pub struct Screenshot {
+    pub handle: u32,
+    /// `None` if the screenshot has no type
+    pub screenshot_type: Option<ScreenshotType>,
+}
+
+impl FromEventData for Screenshot {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        Screenshot {
+            handle: x.screenshot.handle,
+            screenshot_type: ScreenshotType::from_sys(x.screenshot.type_ as sys::EVRScreenshotType),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct ScreenshotProgress {
+    pub progress: f32,
+}
+
+impl FromEventData for ScreenshotProgress {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        ScreenshotProgress {
+            progress: x.screenshotProgress.progress,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SpatialAnchor {
+    pub handle: u32,
+}
+
+impl FromEventData for SpatialAnchor {
+    unsafe fn from_event_data(x: &sys::VREvent_Data_t) -> Self {
+        SpatialAnchor {
+            handle: x.spatialAnchor.unHandle,
+        }
+    }
+}
+
 #[allow(non_camel_case_types, deprecated)]
 #[derive(Debug, Copy, Clone)]
 pub enum Event {
@@ -270,30 +344,30 @@ pub enum Event {
     OverlayGamepadFocusLost,
     OverlaySharedTextureChanged,
     /// Screenshot button combo was pressed, Dashboard should request a screenshot
-    ScreenshotTriggered,
+    ScreenshotTriggered(Screenshot),
     /// Sent to overlays when a SetOverlayRaw or SetOverlayfromFail fails to load
     ImageFailed,
     DashboardOverlayCreated,
     SwitchGamepadFocus,
     /// Sent by vrclient application to compositor to take a screenshot
-    RequestScreenshot,
+    RequestScreenshot(Screenshot),
     /// Sent by compositor to the application that the screenshot has been taken
-    ScreenshotTaken,
+    ScreenshotTaken(Screenshot),
     /// Sent by compositor to the application that the screenshot failed to be taken
-    ScreenshotFailed,
+    ScreenshotFailed(Screenshot),
     /// Sent by compositor to the dashboard that a completed screenshot was submitted
-    SubmitScreenshotToDashboard,
+    SubmitScreenshotToDashboard(Screenshot),
     /// Sent by compositor to the dashboard that a completed screenshot was submitted
-    ScreenshotProgressToDashboard,
+    ScreenshotProgressToDashboard(ScreenshotProgress),
     PrimaryDashboardDeviceChanged,
     RoomViewShown,
     RoomViewHidden,
     ShowUI,
     ShowDevTools,
-    Notification_Shown,
-    Notification_Hidden,
-    Notification_BeginInteraction,
-    Notification_Destroyed,
+    Notification_Shown(Notification),
+    Notification_Hidden(Notification),
+    Notification_BeginInteraction(Notification),
+    Notification_Destroyed(Notification),
     /// The application has been asked to quit
     Quit(Process),
     ProcessQuit(Process),
@@ -335,10 +409,10 @@ pub enum Event {
     MCImageUpdated,
     FirmwareUpdateStarted,
     FirmwareUpdateFinished,
-    KeyboardClosed,
+    KeyboardClosed(Keyboard),
     KeyboardCharInput(Keyboard),
     /// Sent when DONE button clicked on keyboard
-    KeyboardDone,
+    KeyboardDone(Keyboard),
     ApplicationTransitionStarted,
     ApplicationTransitionAborted,
     ApplicationTransitionNewAppStarted,
@@ -375,10 +449,10 @@ pub enum Event {
     Input_ProgressUpdate,
     Input_TrackerActivated,
     Input_BindingsUpdated,
-    SpatialAnchors_PoseUpdated,
-    SpatialAnchors_DescriptorUpdated,
-    SpatialAnchors_RequestPoseUpdate,
-    SpatialAnchors_RequestDescriptorUpdate,
+    SpatialAnchors_PoseUpdated(SpatialAnchor),
+    SpatialAnchors_DescriptorUpdated(SpatialAnchor),
+    SpatialAnchors_RequestPoseUpdate(SpatialAnchor),
+    SpatialAnchors_RequestDescriptorUpdate(SpatialAnchor),
     SystemReport_Started,
 
     VendorSpecific(sys::EVREventType),
@@ -466,16 +540,18 @@ impl Event {
             sys::EVREventType_VREvent_OverlayGamepadFocusGained => OverlayGamepadFocusGained,
             sys::EVREventType_VREvent_OverlayGamepadFocusLost => OverlayGamepadFocusLost,
             sys::EVREventType_VREvent_OverlaySharedTextureChanged => OverlaySharedTextureChanged,
-            sys::EVREventType_VREvent_ScreenshotTriggered => ScreenshotTriggered,
+            sys::EVREventType_VREvent_ScreenshotTriggered => ScreenshotTriggered(get(data)),
             sys::EVREventType_VREvent_ImageFailed => ImageFailed,
             sys::EVREventType_VREvent_DashboardOverlayCreated => DashboardOverlayCreated,
             sys::EVREventType_VREvent_SwitchGamepadFocus => SwitchGamepadFocus,
-            sys::EVREventType_VREvent_RequestScreenshot => RequestScreenshot,
-            sys::EVREventType_VREvent_ScreenshotTaken => ScreenshotTaken,
-            sys::EVREventType_VREvent_ScreenshotFailed => ScreenshotFailed,
-            sys::EVREventType_VREvent_SubmitScreenshotToDashboard => SubmitScreenshotToDashboard,
+            sys::EVREventType_VREvent_RequestScreenshot => RequestScreenshot(get(data)),
+            sys::EVREventType_VREvent_ScreenshotTaken => ScreenshotTaken(get(data)),
+            sys::EVREventType_VREvent_ScreenshotFailed => ScreenshotFailed(get(data)),
+            sys::EVREventType_VREvent_SubmitScreenshotToDashboard => {
+                SubmitScreenshotToDashboard(get(data))
+            }
             sys::EVREventType_VREvent_ScreenshotProgressToDashboard => {
-                ScreenshotProgressToDashboard
+                ScreenshotProgressToDashboard(get(data))
             }
             sys::EVREventType_VREvent_PrimaryDashboardDeviceChanged => {
                 PrimaryDashboardDeviceChanged
@@ -484,12 +560,12 @@ impl Event {
             sys::EVREventType_VREvent_RoomViewHidden => RoomViewHidden,
             sys::EVREventType_VREvent_ShowUI => ShowUI,
             sys::EVREventType_VREvent_ShowDevTools => ShowDevTools,
-            sys::EVREventType_VREvent_Notification_Shown => Notification_Shown,
-            sys::EVREventType_VREvent_Notification_Hidden => Notification_Hidden,
+            sys::EVREventType_VREvent_Notification_Shown => Notification_Shown(get(data)),
+            sys::EVREventType_VREvent_Notification_Hidden => Notification_Hidden(get(data)),
             sys::EVREventType_VREvent_Notification_BeginInteraction => {
-                Notification_BeginInteraction
+                Notification_BeginInteraction(get(data))
             }
-            sys::EVREventType_VREvent_Notification_Destroyed => Notification_Destroyed,
+            sys::EVREventType_VREvent_Notification_Destroyed => Notification_Destroyed(get(data)),
             sys::EVREventType_VREvent_Quit => Quit(get(data)),
             sys::EVREventType_VREvent_ProcessQuit => ProcessQuit(get(data)),
             sys::EVREventType_VREvent_QuitAborted_UserPrompt => QuitAborted_UserPrompt(get(data)),
@@ -555,9 +631,9 @@ impl Event {
             sys::EVREventType_VREvent_MCImageUpdated => MCImageUpdated,
             sys::EVREventType_VREvent_FirmwareUpdateStarted => FirmwareUpdateStarted,
             sys::EVREventType_VREvent_FirmwareUpdateFinished => FirmwareUpdateFinished,
-            sys::EVREventType_VREvent_KeyboardClosed => KeyboardClosed,
+            sys::EVREventType_VREvent_KeyboardClosed => KeyboardClosed(get(data)),
             sys::EVREventType_VREvent_KeyboardCharInput => KeyboardCharInput(get(data)),
-            sys::EVREventType_VREvent_KeyboardDone => KeyboardDone,
+            sys::EVREventType_VREvent_KeyboardDone => KeyboardDone(get(data)),
             sys::EVREventType_VREvent_ApplicationTransitionStarted => ApplicationTransitionStarted,
             sys::EVREventType_VREvent_ApplicationTransitionAborted => ApplicationTransitionAborted,
             sys::EVREventType_VREvent_ApplicationTransitionNewAppStarted => {
@@ -628,15 +704,17 @@ impl Event {
             sys::EVREventType_VREvent_Input_ProgressUpdate => Input_ProgressUpdate,
             sys::EVREventType_VREvent_Input_TrackerActivated => Input_TrackerActivated,
             sys::EVREventType_VREvent_Input_BindingsUpdated => Input_BindingsUpdated,
-            sys::EVREventType_VREvent_SpatialAnchors_PoseUpdated => SpatialAnchors_PoseUpdated,
+            sys::EVREventType_VREvent_SpatialAnchors_PoseUpdated => {
+                SpatialAnchors_PoseUpdated(get(data))
+            }
             sys::EVREventType_VREvent_SpatialAnchors_DescriptorUpdated => {
-                SpatialAnchors_DescriptorUpdated
+                SpatialAnchors_DescriptorUpdated(get(data))
             }
             sys::EVREventType_VREvent_SpatialAnchors_RequestPoseUpdate => {
-                SpatialAnchors_RequestPoseUpdate
+                SpatialAnchors_RequestPoseUpdate(get(data))
             }
             sys::EVREventType_VREvent_SpatialAnchors_RequestDescriptorUpdate => {
-                SpatialAnchors_RequestDescriptorUpdate
+                SpatialAnchors_RequestDescriptorUpdate(get(data))
             }
             sys::EVREventType_VREvent_SystemReport_Started => SystemReport_Started,
             x if x >= sys::EVREventType_VREvent_VendorSpecific_Reserved_Start
@@ -650,3 +728,27 @@ impl Event {
 }
 
 pub use crate::sys::PropertyContainerHandle_t as PropertyContainerHandle;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn keyboard_text() {
+        let keyboard = |new_input: &[u8]| {
+            let mut x = Keyboard {
+                new_input: [0; 8],
+                user_value: 0,
+            };
+            x.new_input[..new_input.len()].copy_from_slice(new_input);
+            x
+        };
+        assert_eq!(keyboard(b"a").text(), "a");
+        assert_eq!(keyboard(b"a").char(), Some('a'));
+        assert_eq!(keyboard("é".as_bytes()).char(), Some('é'));
+        assert_eq!(keyboard(b"\x08").char(), Some('\x08'));
+        assert_eq!(keyboard(b"12345678").text(), "12345678");
+        assert_eq!(keyboard(b"").char(), None);
+        assert_eq!(keyboard(b"ab\xC3").text(), "ab");
+    }
+}
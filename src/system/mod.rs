@@ -396,6 +396,8 @@ impl System {
     /// 5ms.
     ///
     /// Vive controller haptics respond to axis 0. OpenVR seems to reject durations longer than 3999us.
+    ///
+    /// This is deprecated by SteamVR in favor of `Input::trigger_haptic_vibration_action`.
     pub fn trigger_haptic_pulse(&self, device: TrackedDeviceIndex, axis: u32, microseconds: u16) {
         unsafe { self.0.TriggerHapticPulse.unwrap()(device, axis, microseconds) }
     }
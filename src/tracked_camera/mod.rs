@@ -0,0 +1,314 @@
+//! Access to the cameras of tracked devices, such as the passthrough camera of the HMD.
+//!
+//! Frames are read from a `CameraStream`, which holds the video streaming service for as long as it is alive.
+
+use std::{error::Error, ffi::CStr, fmt::Display, mem};
+
+use openvr_sys as sys;
+use openvr_sys::{
+    EVRTrackedCameraError, EVRTrackedCameraError_VRTrackedCameraError_None, TrackedCameraHandle_t,
+};
+
+use crate::{TrackedCamera, TrackedDeviceIndex, TrackedDevicePose};
+
+pub mod model;
+
+pub use self::model::{CameraModel, CameraModelError, Ray};
+
+#[derive(Debug)]
+pub struct VRTrackedCameraError(pub EVRTrackedCameraError);
+impl Display for VRTrackedCameraError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EVRTrackedCameraError {}", self.0)
+    }
+}
+impl Error for VRTrackedCameraError {}
+impl From<EVRTrackedCameraError> for VRTrackedCameraError {
+    fn from(err: EVRTrackedCameraError) -> Self {
+        VRTrackedCameraError(err)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TrackedCameraFrameType {
+    /** This is the camera video frame size in pixels, still distorted. */
+    Distorted = sys::EVRTrackedCameraFrameType_VRTrackedCameraFrameType_Distorted as isize,
+    /** In pixels, an undistorted inscribed rectangle region without invalid regions. This size is subject to
+     * changes shortly. */
+    Undistorted = sys::EVRTrackedCameraFrameType_VRTrackedCameraFrameType_Undistorted as isize,
+    /** In pixels, maximum undistorted with invalid regions. Non zero alpha component identifies valid regions. */
+    MaximumUndistorted =
+        sys::EVRTrackedCameraFrameType_VRTrackedCameraFrameType_MaximumUndistorted as isize,
+}
+
+impl TrackedCameraFrameType {
+    /// Returns `None` for unknown frame types.
+    pub fn from_sys(ty: sys::EVRTrackedCameraFrameType) -> Option<Self> {
+        use self::TrackedCameraFrameType::*;
+        Some(match ty {
+            sys::EVRTrackedCameraFrameType_VRTrackedCameraFrameType_Distorted => Distorted,
+            sys::EVRTrackedCameraFrameType_VRTrackedCameraFrameType_Undistorted => Undistorted,
+            sys::EVRTrackedCameraFrameType_VRTrackedCameraFrameType_MaximumUndistorted => {
+                MaximumUndistorted
+            }
+            _ => return None,
+        })
+    }
+}
+
+/// The size of the frames of one type
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct CameraFrameSize {
+    pub width: u32,
+    pub height: u32,
+    /// Size in bytes of the buffer needed to hold a frame
+    pub frame_buffer_size: u32,
+}
+
+/// The pinhole parameters of a camera, in pixels
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CameraIntrinsics {
+    pub focal_length: [f32; 2],
+    pub center: [f32; 2],
+}
+
+impl TrackedCamera {
+    /** Returns the name of the error, e.g. "VRTrackedCameraError_NoFrameAvailable" */
+    pub fn error_name(&self, error: &VRTrackedCameraError) -> String {
+        unsafe {
+            CStr::from_ptr(self.0.GetCameraErrorNameFromEnum.unwrap()(error.0))
+                .to_string_lossy()
+                .into_owned()
+        }
+    }
+
+    /** For convenience, same as tracked property request Prop_HasCamera_Bool */
+    pub fn has_camera(
+        &self,
+        device_index: TrackedDeviceIndex,
+    ) -> Result<bool, VRTrackedCameraError> {
+        let mut has_camera = false;
+        let result = unsafe { self.0.HasCamera.unwrap()(device_index, &mut has_camera) };
+        if result == EVRTrackedCameraError_VRTrackedCameraError_None {
+            Ok(has_camera)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Gets size of the image frame. */
+    pub fn get_camera_frame_size(
+        &self,
+        device_index: TrackedDeviceIndex,
+        frame_type: TrackedCameraFrameType,
+    ) -> Result<CameraFrameSize, VRTrackedCameraError> {
+        let mut size = CameraFrameSize {
+            width: 0,
+            height: 0,
+            frame_buffer_size: 0,
+        };
+        let result = unsafe {
+            self.0.GetCameraFrameSize.unwrap()(
+                device_index,
+                frame_type as sys::EVRTrackedCameraFrameType,
+                &mut size.width,
+                &mut size.height,
+                &mut size.frame_buffer_size,
+            )
+        };
+        if result == EVRTrackedCameraError_VRTrackedCameraError_None {
+            Ok(size)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    pub fn get_camera_intrinsics(
+        &self,
+        device_index: TrackedDeviceIndex,
+        camera_index: u32,
+        frame_type: TrackedCameraFrameType,
+    ) -> Result<CameraIntrinsics, VRTrackedCameraError> {
+        let mut focal_length = sys::HmdVector2_t { v: [0.0; 2] };
+        let mut center = sys::HmdVector2_t { v: [0.0; 2] };
+        let result = unsafe {
+            self.0.GetCameraIntrinsics.unwrap()(
+                device_index,
+                camera_index,
+                frame_type as sys::EVRTrackedCameraFrameType,
+                &mut focal_length,
+                &mut center,
+            )
+        };
+        if result == EVRTrackedCameraError_VRTrackedCameraError_None {
+            Ok(CameraIntrinsics {
+                focal_length: focal_length.v,
+                center: center.v,
+            })
+        } else {
+            Err(result.into())
+        }
+    }
+
+    pub fn get_camera_projection(
+        &self,
+        device_index: TrackedDeviceIndex,
+        camera_index: u32,
+        frame_type: TrackedCameraFrameType,
+        z_near: f32,
+        z_far: f32,
+    ) -> Result<[[f32; 4]; 4], VRTrackedCameraError> {
+        let mut projection = sys::HmdMatrix44_t { m: [[0.0; 4]; 4] };
+        let result = unsafe {
+            self.0.GetCameraProjection.unwrap()(
+                device_index,
+                camera_index,
+                frame_type as sys::EVRTrackedCameraFrameType,
+                z_near,
+                z_far,
+                &mut projection,
+            )
+        };
+        if result == EVRTrackedCameraError_VRTrackedCameraError_None {
+            Ok(projection.m)
+        } else {
+            Err(result.into())
+        }
+    }
+
+    /** Acquiring streaming service permits video streaming for the caller. Releasing hints the system that video
+     * services do not need to be maintained for this client. If the camera has not already been activated, a one
+     * time spin up may incur some auto exposure as well as initial streaming frame delays. The camera should be
+     * considered a global resource accessible for shared consumption but not exclusive to any caller. The camera may
+     * go inactive due to lack of active consumers or headset idleness.
+     *
+     * The service is released when the returned stream is dropped. */
+    pub fn acquire_video_streaming_service(
+        &self,
+        device_index: TrackedDeviceIndex,
+    ) -> Result<CameraStream<'_>, VRTrackedCameraError> {
+        let mut handle = sys::INVALID_TRACKED_CAMERA_HANDLE;
+        let result =
+            unsafe { self.0.AcquireVideoStreamingService.unwrap()(device_index, &mut handle) };
+        if result == EVRTrackedCameraError_VRTrackedCameraError_None {
+            Ok(CameraStream {
+                camera: self,
+                handle,
+                device_index,
+            })
+        } else {
+            Err(result.into())
+        }
+    }
+}
+
+/// Information about a frame read from a `CameraStream`
+#[derive(Debug, Copy, Clone)]
+pub struct CameraFrameHeader {
+    /// The type of the frame as reported by OpenVR
+    pub frame_type: TrackedCameraFrameType,
+    pub width: u32,
+    pub height: u32,
+    pub bytes_per_pixel: u32,
+    /// Increases with every new frame from the camera
+    pub frame_sequence: u32,
+    /// The pose of the device the camera is attached to when the frame was taken
+    pub pose: TrackedDevicePose,
+    pub exposure_time: u64,
+}
+
+/// A frame read from a `CameraStream`
+#[derive(Debug, Clone)]
+pub struct CameraFrame {
+    pub header: CameraFrameHeader,
+    /// `CameraFrameSize::frame_buffer_size` bytes, starting with `width * height * bytes_per_pixel` bytes of pixel
+    /// data
+    pub data: Vec<u8>,
+}
+
+/// The video streaming service of one device's camera, released when dropped.
+pub struct CameraStream<'a> {
+    camera: &'a TrackedCamera,
+    handle: TrackedCameraHandle_t,
+    device_index: TrackedDeviceIndex,
+}
+
+impl<'a> CameraStream<'a> {
+    pub fn raw(&self) -> TrackedCameraHandle_t {
+        self.handle
+    }
+
+    pub fn device_index(&self) -> TrackedDeviceIndex {
+        self.device_index
+    }
+
+    /** Gets size of the image frame. Query this once to size the buffer passed to `next_frame_into`. */
+    pub fn frame_size(
+        &self,
+        frame_type: TrackedCameraFrameType,
+    ) -> Result<CameraFrameSize, VRTrackedCameraError> {
+        self.camera
+            .get_camera_frame_size(self.device_index, frame_type)
+    }
+
+    /** Copies the image frame into a caller's provided buffer. The image data is currently provided as RGBA data, 4
+     * bytes per pixel.
+     *
+     * Allocates a new buffer on every call; use `next_frame_into` to reuse one instead.
+     * Returns `None` if no frame is available yet, e.g. while the camera is spinning up. */
+    pub fn next_frame(
+        &self,
+        frame_type: TrackedCameraFrameType,
+    ) -> Result<Option<CameraFrame>, VRTrackedCameraError> {
+        let size = self.frame_size(frame_type)?;
+        let mut data = vec![0u8; size.frame_buffer_size as usize];
+        Ok(self
+            .next_frame_into(frame_type, &mut data)?
+            .map(|header| CameraFrame { header, data }))
+    }
+
+    /** Copies the image frame into a caller's provided buffer, which should be at least
+     * `CameraFrameSize::frame_buffer_size` bytes as returned by `frame_size`.
+     *
+     * Returns `None` if no frame is available yet, e.g. while the camera is spinning up. */
+    pub fn next_frame_into(
+        &self,
+        frame_type: TrackedCameraFrameType,
+        buffer: &mut [u8],
+    ) -> Result<Option<CameraFrameHeader>, VRTrackedCameraError> {
+        let mut header: sys::CameraVideoStreamFrameHeader_t = unsafe { mem::zeroed() };
+        let result = unsafe {
+            self.camera.0.GetVideoStreamFrameBuffer.unwrap()(
+                self.handle,
+                frame_type as sys::EVRTrackedCameraFrameType,
+                buffer.as_mut_ptr() as *mut _,
+                buffer.len() as u32,
+                &mut header,
+                mem::size_of::<sys::CameraVideoStreamFrameHeader_t>() as u32,
+            )
+        };
+        if result == sys::EVRTrackedCameraError_VRTrackedCameraError_NoFrameAvailable {
+            return Ok(None);
+        }
+        if result != EVRTrackedCameraError_VRTrackedCameraError_None {
+            return Err(result.into());
+        }
+        Ok(Some(CameraFrameHeader {
+            frame_type: TrackedCameraFrameType::from_sys(header.eFrameType).unwrap_or(frame_type),
+            width: header.nWidth,
+            height: header.nHeight,
+            bytes_per_pixel: header.nBytesPerPixel,
+            frame_sequence: header.nFrameSequence,
+            pose: header.trackedDevicePose.into(),
+            exposure_time: header.ulFrameExposureTime,
+        }))
+    }
+}
+
+impl<'a> Drop for CameraStream<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            self.camera.0.ReleaseVideoStreamingService.unwrap()(self.handle);
+        }
+    }
+}
@@ -0,0 +1,218 @@
+//! Mapping between camera pixels and rays, for locating the contents of camera frames in the world.
+//!
+//! Camera space follows the OpenVR convention: the camera looks down -Z with +Y up and +X to the right. Pixel
+//! coordinates have their origin in the top left corner of the frame, with +Y down.
+
+use std::{error::Error, fmt::Display};
+
+use super::{CameraIntrinsics, TrackedCameraFrameType, VRTrackedCameraError};
+use crate::system::TrackedPropertyError;
+use crate::{property, System, TrackedCamera, TrackedDeviceIndex, TrackedDevicePose};
+
+/// Why `CameraModel::from_device` failed
+#[derive(Debug)]
+pub enum CameraModelError {
+    /// Reading the `CameraToHeadTransform_Matrix34` property failed
+    Property(TrackedPropertyError),
+    /// Reading the intrinsics failed
+    Camera(VRTrackedCameraError),
+}
+impl Display for CameraModelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CameraModelError::Property(e) => write!(f, "camera to head transform: {}", e),
+            CameraModelError::Camera(e) => write!(f, "camera intrinsics: {}", e),
+        }
+    }
+}
+impl Error for CameraModelError {}
+impl From<TrackedPropertyError> for CameraModelError {
+    fn from(err: TrackedPropertyError) -> Self {
+        CameraModelError::Property(err)
+    }
+}
+impl From<VRTrackedCameraError> for CameraModelError {
+    fn from(err: VRTrackedCameraError) -> Self {
+        CameraModelError::Camera(err)
+    }
+}
+
+/// A half-line starting at `origin`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Ray {
+    pub origin: [f32; 3],
+    /// Unit length
+    pub direction: [f32; 3],
+}
+
+impl Ray {
+    /// Applies a rigid transform, e.g. a device-to-absolute-tracking pose, to the ray.
+    pub fn transform(&self, m: &[[f32; 4]; 3]) -> Ray {
+        Ray {
+            origin: transform_point(m, self.origin),
+            direction: normalize(rotate(m, self.direction)),
+        }
+    }
+}
+
+/// A pinhole model of one camera of a tracked device.
+///
+/// Lens distortion is not modelled, so only undistorted frame types are supported: build the model from the
+/// intrinsics of `TrackedCameraFrameType::Undistorted` or `MaximumUndistorted`, and use it with frames of that type.
+/// Pixels of `Distorted` frames will map to the wrong rays.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CameraModel {
+    pub intrinsics: CameraIntrinsics,
+    /// Camera space to head space, from the `CameraToHeadTransform_Matrix34` property of the device
+    pub camera_to_head: [[f32; 4]; 3],
+}
+
+impl CameraModel {
+    pub fn new(intrinsics: CameraIntrinsics, camera_to_head: [[f32; 4]; 3]) -> Self {
+        CameraModel {
+            intrinsics,
+            camera_to_head,
+        }
+    }
+
+    /// Reads the intrinsics of the device's first camera for `frame_type`, and its camera to head transform from the
+    /// `CameraToHeadTransform_Matrix34` property.
+    ///
+    /// `frame_type` should be an undistorted frame type.
+    pub fn from_device(
+        system: &System,
+        tracked_camera: &TrackedCamera,
+        device: TrackedDeviceIndex,
+        frame_type: TrackedCameraFrameType,
+    ) -> Result<Self, CameraModelError> {
+        let camera_to_head = system
+            .matrix34_tracked_device_property(device, property::CameraToHeadTransform_Matrix34)?;
+        let intrinsics = tracked_camera.get_camera_intrinsics(device, 0, frame_type)?;
+        Ok(CameraModel::new(intrinsics, camera_to_head))
+    }
+
+    /// The ray in head space through the center of the given pixel position.
+    pub fn unproject(&self, pixel: [f32; 2]) -> Ray {
+        let [fx, fy] = self.intrinsics.focal_length;
+        let [cx, cy] = self.intrinsics.center;
+        let direction = [(pixel[0] - cx) / fx, -(pixel[1] - cy) / fy, -1.0];
+        Ray {
+            origin: [0.0; 3],
+            direction: normalize(direction),
+        }
+        .transform(&self.camera_to_head)
+    }
+
+    /// The pixel position a point in head space appears at, or `None` if the point is not in front of the camera.
+    ///
+    /// The result may lie outside the frame.
+    pub fn project(&self, point: [f32; 3]) -> Option<[f32; 2]> {
+        let m = &self.camera_to_head;
+        let offset = [point[0] - m[0][3], point[1] - m[1][3], point[2] - m[2][3]];
+        // The inverse of a rotation is its transpose
+        let mut camera = [0.0; 3];
+        for (i, x) in camera.iter_mut().enumerate() {
+            *x = m[0][i] * offset[0] + m[1][i] * offset[1] + m[2][i] * offset[2];
+        }
+        if camera[2] >= 0.0 {
+            return None;
+        }
+        let [fx, fy] = self.intrinsics.focal_length;
+        let [cx, cy] = self.intrinsics.center;
+        Some([
+            cx + fx * camera[0] / -camera[2],
+            cy - fy * camera[1] / -camera[2],
+        ])
+    }
+
+    /// The ray in tracking space through a pixel of a frame taken at `pose`, as found in `CameraFrameHeader::pose`.
+    pub fn unproject_with_pose(&self, pose: &TrackedDevicePose, pixel: [f32; 2]) -> Ray {
+        self.unproject(pixel)
+            .transform(pose.device_to_absolute_tracking())
+    }
+}
+
+fn rotate(m: &[[f32; 4]; 3], v: [f32; 3]) -> [f32; 3] {
+    let mut result = [0.0; 3];
+    for (x, row) in result.iter_mut().zip(m) {
+        *x = row[0] * v[0] + row[1] * v[1] + row[2] * v[2];
+    }
+    result
+}
+
+fn transform_point(m: &[[f32; 4]; 3], p: [f32; 3]) -> [f32; 3] {
+    let r = rotate(m, p);
+    [r[0] + m[0][3], r[1] + m[1][3], r[2] + m[2][3]]
+}
+
+fn normalize(v: [f32; 3]) -> [f32; 3] {
+    let len = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    [v[0] / len, v[1] / len, v[2] / len]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const IDENTITY: [[f32; 4]; 3] = [
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+    ];
+
+    fn model(camera_to_head: [[f32; 4]; 3]) -> CameraModel {
+        CameraModel::new(
+            CameraIntrinsics {
+                focal_length: [400.0, 400.0],
+                center: [320.0, 240.0],
+            },
+            camera_to_head,
+        )
+    }
+
+    fn assert_close(a: &[f32], b: &[f32]) {
+        assert!(
+            a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-4),
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn unproject_center() {
+        let ray = model(IDENTITY).unproject([320.0, 240.0]);
+        assert_close(&ray.origin, &[0.0; 3]);
+        assert_close(&ray.direction, &[0.0, 0.0, -1.0]);
+
+        // Up and to the right of the center, one focal length from it
+        let ray = model(IDENTITY).unproject([720.0, -160.0]);
+        let d = 1.0 / 3f32.sqrt();
+        assert_close(&ray.direction, &[d, d, -d]);
+    }
+
+    #[test]
+    fn round_trip() {
+        // Rotated 90 degrees about Y, so the camera looks down -X, and offset from the head
+        let model = model([
+            [0.0, 0.0, 1.0, 0.05],
+            [0.0, 1.0, 0.0, -0.02],
+            [-1.0, 0.0, 0.0, -0.1],
+        ]);
+        for pixel in [[320.0, 240.0], [10.0, 470.0], [600.0, 33.0]] {
+            let ray = model.unproject(pixel);
+            assert_close(&ray.origin, &[0.05, -0.02, -0.1]);
+            let point = [
+                ray.origin[0] + 2.5 * ray.direction[0],
+                ray.origin[1] + 2.5 * ray.direction[1],
+                ray.origin[2] + 2.5 * ray.direction[2],
+            ];
+            assert_close(&model.project(point).unwrap(), &pixel);
+        }
+        assert_close(
+            &model.unproject([320.0, 240.0]).direction,
+            &[-1.0, 0.0, 0.0],
+        );
+        assert_eq!(model.project([1.0, 0.0, -0.1]), None);
+    }
+}
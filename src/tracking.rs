@@ -1,6 +1,7 @@
 use openvr_sys as sys;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum TrackingUniverseOrigin {
     Seated = sys::ETrackingUniverseOrigin_TrackingUniverseSeated as isize,
     Standing = sys::ETrackingUniverseOrigin_TrackingUniverseStanding as isize,